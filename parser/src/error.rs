@@ -0,0 +1,297 @@
+use super::*;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Something that was either found or expected at the position of an error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Info<T> {
+    Token(T),
+    EndOfInput,
+    Label(Cow<'static, str>),
+}
+
+impl<T> fmt::Display for Info<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Info::Token(token) => write!(f, "{:?}", token),
+            Info::EndOfInput => f.write_str("end of input"),
+            Info::Label(label) => f.write_str(label),
+        }
+    }
+}
+
+/// The error produced when a parser fails, describing what went wrong at which
+/// position of the input.
+pub struct Error<I>
+where
+    I: Stream,
+{
+    position: I::Position,
+    unexpected: Option<Info<I::Item>>,
+    expected: Vec<Info<I::Item>>,
+    fatal: bool,
+    needed: Option<usize>,
+}
+
+impl<I> Error<I>
+where
+    I: Stream,
+{
+    pub fn new(position: I::Position) -> Self {
+        Error {
+            position,
+            unexpected: None,
+            expected: Vec::new(),
+            fatal: false,
+            needed: None,
+        }
+    }
+
+    /// An error signaling that the input ran out before parsing could finish,
+    /// and that at least `needed` more items are required to continue. These
+    /// errors are fatal, so parsing can be retried once more input is available.
+    pub fn incomplete(position: I::Position, needed: usize) -> Self {
+        Error {
+            needed: Some(needed),
+            ..Error::new(position).into_fatal()
+        }
+    }
+
+    /// An error at the current position of `input` that reports the next token,
+    /// or the end of input, as unexpected. At the end of a partial stream, this
+    /// is an incomplete error instead.
+    pub fn at(input: &I) -> Self {
+        if input.is_empty() && input.is_partial() {
+            return Error::incomplete(input.position(), 1);
+        }
+        let mut copy = *input;
+        let unexpected = copy.uncons_map(Some).map_or(Info::EndOfInput, Info::Token);
+        Error::new(input.position()).with_unexpected(unexpected)
+    }
+
+    pub fn with_unexpected(mut self, info: Info<I::Item>) -> Self {
+        self.unexpected = Some(info);
+        self
+    }
+
+    pub fn with_expected(mut self, info: Info<I::Item>) -> Self {
+        self.expected.push(info);
+        self
+    }
+
+    /// Marks this error as fatal, which prevents enclosing parsers from trying
+    /// any alternatives.
+    pub fn into_fatal(mut self) -> Self {
+        self.fatal = true;
+        self
+    }
+
+    /// Replaces everything this error expected with a single label.
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.expected = vec![Info::Label(label.into())];
+        self
+    }
+
+    /// Combines the errors of two alternatives, keeping whichever got furthest
+    /// into the input, or the expectations of both if they failed at the same
+    /// position.
+    pub fn merge(mut self, other: Self) -> Self {
+        match self.position.cmp(&other.position) {
+            Ordering::Greater => self,
+            Ordering::Less => other,
+            Ordering::Equal => {
+                self.unexpected = self.unexpected.or(other.unexpected);
+                self.expected.extend(other.expected);
+                self.fatal |= other.fatal;
+                self.needed = self.needed.max(other.needed);
+                self
+            }
+        }
+    }
+
+    pub fn position(&self) -> &I::Position {
+        &self.position
+    }
+
+    pub fn unexpected(&self) -> Option<&Info<I::Item>> {
+        self.unexpected.as_ref()
+    }
+
+    pub fn expected(&self) -> &[Info<I::Item>] {
+        &self.expected
+    }
+
+    pub fn is_fatal(&self) -> bool {
+        self.fatal
+    }
+
+    /// The minimum number of additional items needed, if this error was caused
+    /// by reaching the end of a partial stream.
+    pub fn needed(&self) -> Option<usize> {
+        self.needed
+    }
+
+    pub fn is_incomplete(&self) -> bool {
+        self.needed.is_some()
+    }
+}
+
+impl<I> Clone for Error<I>
+where
+    I: Stream,
+    I::Position: Clone,
+{
+    fn clone(&self) -> Self {
+        Error {
+            position: self.position.clone(),
+            unexpected: self.unexpected.clone(),
+            expected: self.expected.clone(),
+            fatal: self.fatal,
+            needed: self.needed,
+        }
+    }
+}
+
+impl<I> PartialEq for Error<I>
+where
+    I: Stream,
+    I::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position
+            && self.unexpected == other.unexpected
+            && self.expected == other.expected
+            && self.fatal == other.fatal
+            && self.needed == other.needed
+    }
+}
+
+impl<I> fmt::Debug for Error<I>
+where
+    I: Stream,
+    I::Position: fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Error")
+            .field("position", &self.position)
+            .field("unexpected", &self.unexpected)
+            .field("expected", &self.expected)
+            .field("fatal", &self.fatal)
+            .field("needed", &self.needed)
+            .finish()
+    }
+}
+
+impl<I> fmt::Display for Error<I>
+where
+    I: Stream,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(needed) = self.needed {
+            return write!(f, "needs more data (at least {})", needed);
+        }
+
+        match &self.unexpected {
+            Some(unexpected) => write!(f, "unexpected {}", unexpected)?,
+            None if self.expected.is_empty() => return f.write_str("invalid input"),
+            None => {}
+        }
+
+        if self.unexpected.is_some() && !self.expected.is_empty() {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", Expected(&self.expected))
+    }
+}
+
+/// Formats a list of expectations as "expected a, b or c", or as nothing at all
+/// if the list is empty. Expectations that read the same are only listed once.
+pub(crate) struct Expected<'a, T>(pub(crate) &'a [Info<T>]);
+
+impl<T> fmt::Display for Expected<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut expected: Vec<std::string::String> = Vec::new();
+        for info in self.0 {
+            let info = info.to_string();
+            if !expected.contains(&info) {
+                expected.push(info);
+            }
+        }
+
+        if let Some((last, rest)) = expected.split_last() {
+            f.write_str("expected ")?;
+            if let Some((first, rest)) = rest.split_first() {
+                write!(f, "{}", first)?;
+                for info in rest {
+                    write!(f, ", {}", info)?;
+                }
+                f.write_str(" or ")?;
+            }
+            write!(f, "{}", last)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Accumulates the errors that parsers recovered from.
+pub struct Collector<I>
+where
+    I: Stream,
+{
+    errors: RefCell<Vec<Error<I>>>,
+}
+
+impl<I> Collector<I>
+where
+    I: Stream,
+{
+    pub fn new() -> Self {
+        Collector {
+            errors: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn push(&self, error: Error<I>) {
+        self.errors.borrow_mut().push(error);
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.borrow().is_empty()
+    }
+
+    pub fn into_errors(self) -> Vec<Error<I>> {
+        self.errors.into_inner()
+    }
+}
+
+impl<I> Default for Collector<I>
+where
+    I: Stream,
+{
+    fn default() -> Self {
+        Collector::new()
+    }
+}
+
+impl<I> std::error::Error for Error<I>
+where
+    I: Stream,
+    I::Position: fmt::Debug,
+    I::Item: fmt::Debug,
+{
+}
@@ -1,22 +1,79 @@
 pub mod many;
 pub mod many1;
+pub mod repeat;
 pub mod sep_by;
 
 use super::*;
 
+/// Turns the result of parsing an element into the next item of an iterator,
+/// holding on to a fatal error so that the enclosing parser can return it.
+fn next_item<O, I>(result: Result<O, Error<I>>, error: &mut Option<Error<I>>) -> Option<O>
+where
+    I: Stream,
+{
+    match result {
+        Ok(output) => Some(output),
+        Err(e) => {
+            if e.is_fatal() {
+                *error = Some(e);
+            }
+            None
+        }
+    }
+}
+
+fn finish<O, I>(
+    output: Option<O>,
+    error: Option<Error<I>>,
+    position: I::Position,
+) -> Result<O, Error<I>>
+where
+    I: Stream,
+{
+    match error {
+        Some(error) => Err(error),
+        None => output.ok_or_else(|| Error::new(position)),
+    }
+}
+
 #[test]
 fn test_many() {
     let number = digit().many1(|iter| Some(iter.fold(0, |n, d| 10 * n + d)));
     let mut input = "123abc";
-    assert_eq!(number.parse(&mut input), Some(123));
+    assert_eq!(number.parse(&mut input), Ok(123));
     assert_eq!(input, "abc");
 }
 
 #[test]
 fn test_many_mut() {
     let mut stack = vec![2, 3, 5, 7, 11];
-    let parser = from_fn_mut(|_| stack.pop()).collect_many();
+    let parser =
+        from_fn_mut(|input: &mut &str| stack.pop().ok_or_else(|| Error::at(input))).collect_many();
     let mut input = "";
-    assert_eq!(parser.parse_once(&mut input), Some(vec![11, 7, 5, 3, 2]));
+    assert_eq!(parser.parse_once(&mut input), Ok(vec![11, 7, 5, 3, 2]));
     assert_eq!(input, "");
 }
+
+#[test]
+fn test_chain() {
+    let sub = token('-').map(|_| |a: i32, b: i32| a - b);
+    let mut input = "8-3-2)";
+    assert_eq!(
+        digit().map(|d| d as i32).chain_left(sub).parse(&mut input),
+        Ok(3)
+    );
+    assert_eq!(input, ")");
+
+    let pow = token('^').map(|_| |a: u32, b: u32| a.pow(b));
+    assert_eq!(digit().chain_right(pow).parse_to_end("2^3^2"), Ok(512));
+    assert_eq!(digit().chain_right(pow).parse_to_end("7"), Ok(7));
+    assert!(digit().chain_left(pow).parse_to_end("^2").is_err());
+
+    let sub = token('-').map(|_| |a: u32, b: u32| a - b);
+    let mut input = "8-x";
+    assert_eq!(digit().chain_left(sub).parse(&mut input), Ok(8));
+    assert_eq!(input, "-x");
+    let mut input = "2^3^";
+    assert_eq!(digit().chain_right(pow).parse(&mut input), Ok(8));
+    assert_eq!(input, "^");
+}
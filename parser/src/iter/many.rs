@@ -1,7 +1,9 @@
 mod collect_many;
 mod iter;
+#[allow(clippy::module_inception)]
 mod many;
 mod many_mut;
+mod many_till;
 mod skip_many;
 
 use super::*;
@@ -10,4 +12,5 @@ pub use collect_many::*;
 pub use iter::*;
 pub use many::*;
 pub use many_mut::*;
+pub use many_till::*;
 pub use skip_many::*;
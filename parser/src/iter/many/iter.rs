@@ -1,25 +1,75 @@
 use super::*;
 
-#[derive(Clone)]
-pub struct ManyIter<P, I> {
+pub struct ManyIter<P, I>
+where
+    I: Stream,
+{
     parser: P,
     input: I,
+    error: Option<Error<I>>,
+}
+
+impl<P, I> Clone for ManyIter<P, I>
+where
+    P: Clone,
+    I: Stream,
+    I::Position: Clone,
+{
+    fn clone(&self) -> Self {
+        ManyIter {
+            parser: self.parser.clone(),
+            input: self.input,
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<P, I> ManyIter<P, I>
+where
+    I: Stream,
+{
+    /// The input that hasn't been consumed yet. After an incomplete error,
+    /// this starts at the item that couldn't be finished, so that parsing can
+    /// be resumed from there once more input is available.
+    pub fn remaining(&self) -> I {
+        self.input
+    }
+
+    /// The fatal error that ended the iteration, if any, such as an incomplete
+    /// error at the end of a partial stream.
+    pub fn error(&self) -> Option<&Error<I>> {
+        self.error.as_ref()
+    }
 }
 
 impl<P, I> Iterator for ManyIter<P, I>
 where
     P: ParserMut<I>,
+    I: Stream,
 {
     type Item = P::Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.parser.parse_mut(&mut self.input)
+        if self.error.is_some() {
+            return None;
+        }
+        let checkpoint = self.input;
+        let result = self.parser.parse_mut(&mut self.input);
+        if result.as_ref().is_err_and(Error::is_incomplete) {
+            self.input = checkpoint;
+        }
+        next_item(result, &mut self.error)
     }
 }
 
 pub fn iter<P, I>(parser: P, input: I) -> ManyIter<P, I>
 where
     P: ParserMut<I>,
+    I: Stream,
 {
-    ManyIter { parser, input }
+    ManyIter {
+        parser,
+        input,
+        error: None,
+    }
 }
@@ -1,18 +1,23 @@
 use super::*;
 
-pub struct Iter<'a, P, I> {
+pub struct Iter<'a, P, I>
+where
+    I: Stream,
+{
     parser: &'a P,
     input: &'a mut I,
+    error: &'a mut Option<Error<I>>,
 }
 
 impl<P, I> Iterator for Iter<'_, P, I>
 where
     P: Parser<I>,
+    I: Stream,
 {
     type Item = P::Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.parser.parse(self.input)
+        next_item(self.parser.parse(self.input), self.error)
     }
 }
 
@@ -26,15 +31,19 @@ impl<P, F, I, O> ParserOnce<I> for Many<P, F>
 where
     P: Parser<I>,
     F: FnOnce(Iter<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let iter = Iter {
             parser: &self.parser,
             input,
+            error: &mut error,
         };
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -42,13 +51,17 @@ impl<P, F, I, O> ParserMut<I> for Many<P, F>
 where
     P: Parser<I>,
     F: FnMut(Iter<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let iter = Iter {
             parser: &self.parser,
             input,
+            error: &mut error,
         };
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -56,13 +69,17 @@ impl<P, F, I, O> Parser<I> for Many<P, F>
 where
     P: Parser<I>,
     F: Fn(Iter<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let iter = Iter {
             parser: &self.parser,
             input,
+            error: &mut error,
         };
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
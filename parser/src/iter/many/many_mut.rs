@@ -1,18 +1,23 @@
 use super::*;
 
-pub struct IterMut<'a, P, I> {
+pub struct IterMut<'a, P, I>
+where
+    I: Stream,
+{
     parser: &'a mut P,
     input: &'a mut I,
+    error: &'a mut Option<Error<I>>,
 }
 
 impl<P, I> Iterator for IterMut<'_, P, I>
 where
     P: ParserMut<I>,
+    I: Stream,
 {
     type Item = P::Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.parser.parse_mut(self.input)
+        next_item(self.parser.parse_mut(self.input), self.error)
     }
 }
 
@@ -26,15 +31,19 @@ impl<P, F, I, O> ParserOnce<I> for ManyMut<P, F>
 where
     P: ParserMut<I>,
     F: FnOnce(IterMut<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let iter = IterMut {
             parser: &mut self.parser,
             input,
+            error: &mut error,
         };
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -42,13 +51,17 @@ impl<P, F, I, O> ParserMut<I> for ManyMut<P, F>
 where
     P: ParserMut<I>,
     F: FnMut(IterMut<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let iter = IterMut {
             parser: &mut self.parser,
             input,
+            error: &mut error,
         };
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
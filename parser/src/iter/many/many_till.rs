@@ -0,0 +1,109 @@
+use super::*;
+
+pub struct ManyTill<P, E, O> {
+    parser: P,
+    end: E,
+    _marker: PhantomData<O>,
+}
+
+impl<P, E, O> Copy for ManyTill<P, E, O>
+where
+    P: Copy,
+    E: Copy,
+{
+}
+
+impl<P, E, O> Clone for ManyTill<P, E, O>
+where
+    P: Clone,
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        ManyTill {
+            parser: self.parser.clone(),
+            end: self.end.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Collects the outputs of `item` until `end` succeeds, trying `end` first at
+/// every position. Input consumed by `end` is only kept if it succeeds. The
+/// outputs are buffered so that every item is parsed, even if the collection
+/// stops consuming them early.
+fn collect_till<A, B, C, I>(
+    mut item: impl FnMut(&mut I) -> Result<A, Error<I>>,
+    mut end: impl FnMut(&mut I) -> Result<B, Error<I>>,
+    input: &mut I,
+) -> Result<(C, B), Error<I>>
+where
+    C: FromIterator<A>,
+    I: Stream,
+{
+    let mut items = Vec::new();
+    loop {
+        let mut copy = *input;
+        match end(&mut copy) {
+            Ok(output) => {
+                *input = copy;
+                return Ok((items.into_iter().collect(), output));
+            }
+            Err(error) if error.is_fatal() => return Err(error),
+            Err(error) => items.push(item(input).map_err(|other| error.merge(other))?),
+        }
+    }
+}
+
+impl<P, E, I, O> ParserOnce<I> for ManyTill<P, E, O>
+where
+    P: ParserMut<I>,
+    E: ParserMut<I>,
+    O: FromIterator<P::Output>,
+    I: Stream,
+{
+    type Output = (O, E::Output);
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<P, E, I, O> ParserMut<I> for ManyTill<P, E, O>
+where
+    P: ParserMut<I>,
+    E: ParserMut<I>,
+    O: FromIterator<P::Output>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        collect_till(
+            |input| self.parser.parse_mut(input),
+            |input| self.end.parse_mut(input),
+            input,
+        )
+    }
+}
+
+impl<P, E, I, O> Parser<I> for ManyTill<P, E, O>
+where
+    P: Parser<I>,
+    E: Parser<I>,
+    O: FromIterator<P::Output>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        collect_till(
+            |input| self.parser.parse(input),
+            |input| self.end.parse(input),
+            input,
+        )
+    }
+}
+
+pub fn many_till<P, E, O>(parser: P, end: E) -> ManyTill<P, E, O> {
+    ManyTill {
+        parser,
+        end,
+        _marker: PhantomData,
+    }
+}
@@ -1,10 +1,15 @@
+mod chain_left;
+mod chain_right;
 mod collect_many1;
+#[allow(clippy::module_inception)]
 mod many1;
 mod many1_mut;
 mod skip_many1;
 
 use super::*;
 
+pub use chain_left::*;
+pub use chain_right::*;
 pub use collect_many1::*;
 pub use many1::*;
 pub use many1_mut::*;
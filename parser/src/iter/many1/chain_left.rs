@@ -0,0 +1,83 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct ChainLeft<P, Op> {
+    parser: P,
+    op: Op,
+}
+
+/// Parses `parser` followed by any number of `op` and `parser` pairs, yielding
+/// each operand together with the operator in front of it, and folds them with
+/// `f`. Each pair is attempted as a whole, so an operator without a right-hand
+/// operand is left in the input.
+pub(super) fn parse_operands<P, Op, I, O>(
+    parser: &mut P,
+    op: &mut Op,
+    input: &mut I,
+    f: impl FnOnce(&mut dyn Iterator<Item = (Option<Op::Output>, P::Output)>) -> Option<O>,
+) -> Result<O, Error<I>>
+where
+    P: ParserMut<I>,
+    Op: ParserMut<I>,
+    I: Stream,
+{
+    let mut first = true;
+    let operands = from_fn_mut(|input: &mut I| {
+        if std::mem::take(&mut first) {
+            return parser.parse_mut(input).map(|operand| (None, operand));
+        }
+        chain((op.by_mut_ref(), parser.by_mut_ref()))
+            .attempt()
+            .map_once(|(op, operand)| (Some(op), operand))
+            .parse_once(input)
+    });
+    many1_mut(operands, |mut iter: IterMut<'_, _, I>| f(&mut iter)).parse_once(input)
+}
+
+impl<P, Op, I> ParserOnce<I> for ChainLeft<P, Op>
+where
+    P: ParserMut<I>,
+    Op: ParserMut<I>,
+    Op::Output: FnOnce(P::Output, P::Output) -> P::Output,
+    I: Stream,
+{
+    type Output = P::Output;
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<P, Op, I> ParserMut<I> for ChainLeft<P, Op>
+where
+    P: ParserMut<I>,
+    Op: ParserMut<I>,
+    Op::Output: FnOnce(P::Output, P::Output) -> P::Output,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        parse_operands(&mut self.parser, &mut self.op, input, |iter| {
+            let (_, mut output) = iter.next()?;
+            for (op, right) in iter {
+                output = op?(output, right);
+            }
+            Some(output)
+        })
+    }
+}
+
+impl<P, Op, I> Parser<I> for ChainLeft<P, Op>
+where
+    P: Parser<I>,
+    Op: Parser<I>,
+    Op::Output: FnOnce(P::Output, P::Output) -> P::Output,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        chain_left(&self.parser, &self.op).parse_once(input)
+    }
+}
+
+pub fn chain_left<P, Op>(parser: P, op: Op) -> ChainLeft<P, Op> {
+    ChainLeft { parser, op }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct ChainRight<P, Op> {
+    parser: P,
+    op: Op,
+}
+
+/// Folds `x op y op z ...` from the right.
+fn fold_right<O, F>(iter: &mut dyn Iterator<Item = (Option<F>, O)>) -> Option<O>
+where
+    F: FnOnce(O, O) -> O,
+{
+    let (_, first) = iter.next()?;
+    let mut operands = vec![first];
+    let mut ops = Vec::new();
+    for (op, operand) in iter {
+        ops.push(op?);
+        operands.push(operand);
+    }
+
+    let mut output = operands.pop()?;
+    while let Some(op) = ops.pop() {
+        output = op(operands.pop()?, output);
+    }
+    Some(output)
+}
+
+impl<P, Op, I> ParserOnce<I> for ChainRight<P, Op>
+where
+    P: ParserMut<I>,
+    Op: ParserMut<I>,
+    Op::Output: FnOnce(P::Output, P::Output) -> P::Output,
+    I: Stream,
+{
+    type Output = P::Output;
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<P, Op, I> ParserMut<I> for ChainRight<P, Op>
+where
+    P: ParserMut<I>,
+    Op: ParserMut<I>,
+    Op::Output: FnOnce(P::Output, P::Output) -> P::Output,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        parse_operands(&mut self.parser, &mut self.op, input, fold_right)
+    }
+}
+
+impl<P, Op, I> Parser<I> for ChainRight<P, Op>
+where
+    P: Parser<I>,
+    Op: Parser<I>,
+    Op::Output: FnOnce(P::Output, P::Output) -> P::Output,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        chain_right(&self.parser, &self.op).parse_once(input)
+    }
+}
+
+pub fn chain_right<P, Op>(parser: P, op: Op) -> ChainRight<P, Op> {
+    ChainRight { parser, op }
+}
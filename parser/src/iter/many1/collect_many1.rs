@@ -23,10 +23,11 @@ impl<P, I, O> ParserOnce<I> for CollectMany1<P, O>
 where
     P: ParserMut<I>,
     O: FromIterator<P::Output>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
@@ -35,8 +36,9 @@ impl<P, I, O> ParserMut<I> for CollectMany1<P, O>
 where
     P: ParserMut<I>,
     O: FromIterator<P::Output>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser
             .by_mut_ref()
             .many1_mut(|iter| Some(iter.collect()))
@@ -48,8 +50,9 @@ impl<P, I, O> Parser<I> for CollectMany1<P, O>
 where
     P: Parser<I>,
     O: FromIterator<P::Output>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser
             .by_ref()
             .many1(|iter| Some(iter.collect()))
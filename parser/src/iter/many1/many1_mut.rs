@@ -4,22 +4,25 @@ use std::ops::Try;
 pub struct IterMut<'a, P, I>
 where
     P: ParserOnce<I>,
+    I: Stream,
 {
     parser: &'a mut P,
     input: &'a mut I,
+    error: &'a mut Option<Error<I>>,
     first: Option<P::Output>,
 }
 
 impl<P, I> Iterator for IterMut<'_, P, I>
 where
     P: ParserMut<I>,
+    I: Stream,
 {
     type Item = P::Output;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.first
             .take()
-            .or_else(|| self.parser.parse_mut(self.input))
+            .or_else(|| next_item(self.parser.parse_mut(self.input), self.error))
     }
 
     fn try_fold<B, F, R>(&mut self, mut state: B, mut f: F) -> R
@@ -31,7 +34,7 @@ where
             state = f(state, first)?;
         }
 
-        while let Some(output) = self.parser.parse_mut(self.input) {
+        while let Some(output) = next_item(self.parser.parse_mut(self.input), self.error) {
             state = f(state, output)?
         }
 
@@ -49,19 +52,23 @@ impl<P, F, I, O> ParserOnce<I> for Many1Mut<P, F>
 where
     P: ParserMut<I>,
     F: FnOnce(IterMut<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let first = self.parser.parse_mut(input)?;
 
         let iter = IterMut {
             parser: &mut self.parser,
             input,
+            error: &mut error,
             first: Some(first),
         };
 
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -69,17 +76,21 @@ impl<P, F, I, O> ParserMut<I> for Many1Mut<P, F>
 where
     P: ParserMut<I>,
     F: FnMut(IterMut<'_, P, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let first = self.parser.parse_mut(input)?;
 
         let iter = IterMut {
             parser: &mut self.parser,
             input,
+            error: &mut error,
             first: Some(first),
         };
 
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
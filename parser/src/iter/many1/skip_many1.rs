@@ -6,10 +6,11 @@ pub struct SkipMany1<P>(P);
 impl<P, I> ParserOnce<I> for SkipMany1<P>
 where
     P: ParserMut<I>,
+    I: Stream,
 {
     type Output = ();
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
@@ -17,8 +18,9 @@ where
 impl<P, I> ParserMut<I> for SkipMany1<P>
 where
     P: ParserMut<I>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.0
             .by_mut_ref()
             .many1_mut(|iter| {
@@ -32,8 +34,9 @@ where
 impl<P, I> Parser<I> for SkipMany1<P>
 where
     P: Parser<I>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.0
             .by_ref()
             .many1(|iter| {
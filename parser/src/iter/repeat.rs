@@ -0,0 +1,166 @@
+mod collect_repeat;
+mod fold_repeat;
+#[allow(clippy::module_inception)]
+mod repeat;
+mod repeat_mut;
+mod skip_repeat;
+
+use super::*;
+use std::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+
+pub use collect_repeat::*;
+pub use fold_repeat::*;
+pub use repeat::*;
+pub use repeat_mut::*;
+pub use skip_repeat::*;
+
+/// The minimum and maximum number of times a parser is repeated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    min: usize,
+    max: Option<usize>,
+}
+
+/// Something that describes how many times a parser is repeated: either an
+/// exact number or a range. Empty ranges, such as `5..3`, panic.
+pub trait RepeatCount {
+    fn bounds(self) -> Bounds;
+}
+
+impl RepeatCount for Bounds {
+    fn bounds(self) -> Bounds {
+        self
+    }
+}
+
+impl RepeatCount for usize {
+    fn bounds(self) -> Bounds {
+        Bounds {
+            min: self,
+            max: Some(self),
+        }
+    }
+}
+
+macro_rules! range_count {
+    ($($range:ty),*) => {
+        $(
+            impl RepeatCount for $range {
+                fn bounds(self) -> Bounds {
+                    range_bounds(&self)
+                }
+            }
+        )*
+    };
+}
+
+range_count!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    (Bound<usize>, Bound<usize>)
+);
+
+fn range_bounds(range: &impl RangeBounds<usize>) -> Bounds {
+    let min = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n + 1,
+        Bound::Unbounded => 0,
+    };
+    let max = match range.end_bound() {
+        Bound::Included(&n) => Some(n),
+        Bound::Excluded(&n) => Some(n.checked_sub(1).expect("empty repeat count range")),
+        Bound::Unbounded => None,
+    };
+    assert!(max.is_none_or(|max| min <= max), "empty repeat count range");
+    Bounds { min, max }
+}
+
+/// The state shared by the iterators of the repeat family, which stop after
+/// the maximum number of items and hold on to the error if an item fails
+/// before the minimum is reached.
+struct Counter<'a, I>
+where
+    I: Stream,
+{
+    bounds: Bounds,
+    count: usize,
+    error: &'a mut Option<Error<I>>,
+}
+
+impl<I> Counter<'_, I>
+where
+    I: Stream,
+{
+    fn is_done(&self) -> bool {
+        self.bounds.max.is_some_and(|max| self.count >= max)
+    }
+
+    fn next_item<O>(&mut self, result: Result<O, Error<I>>) -> Option<O> {
+        match result {
+            Ok(output) => {
+                self.count += 1;
+                Some(output)
+            }
+            Err(error) => {
+                if error.is_fatal() || self.count < self.bounds.min {
+                    *self.error = Some(error);
+                }
+                None
+            }
+        }
+    }
+}
+
+#[test]
+fn test_repeat() {
+    let year = digit().collect_repeat::<_, Vec<_>>(4);
+    assert_eq!(year.parse_to_end("2024"), Ok(vec![2, 0, 2, 4]));
+    assert!(year.parse_to_end("202").is_err());
+    assert!(year.parse_to_end("20245").is_err());
+
+    let mut input = "12345";
+    let number = digit().fold_repeat(2..=3, 0, |n, d| 10 * n + d);
+    assert_eq!(number.parse(&mut input), Ok(123));
+    assert_eq!(number.parse(&mut input), Ok(45));
+    let error = number.parse(&mut input).unwrap_err();
+    assert_eq!(error.to_string(), "unexpected end of input, expected digit");
+
+    let mut input = "aaab";
+    assert_eq!(token('a').skip_repeat(..2).parse(&mut input), Ok(()));
+    assert_eq!(input, "aab");
+    assert_eq!(token('a').skip_repeat(..).parse(&mut input), Ok(()));
+    assert_eq!(input, "b");
+
+    let sum = digit().repeat(1.., |iter| Some(iter.sum::<u32>()));
+    assert_eq!(sum.parse_to_end("123"), Ok(6));
+    let count = digit().repeat_mut(0..3, |iter| Some(iter.count()));
+    assert_eq!(count.parse_partial("12345"), Ok(2));
+
+    let mut digits = Vec::new();
+    let parser = digit().fold_repeat_mut(2, 0, |n, d| {
+        digits.push(d);
+        n + d
+    });
+    assert_eq!(parser.parse_partial("345"), Ok(7));
+    assert_eq!(digits, vec![3, 4]);
+
+    let mut input = "123";
+    let parser = digit().skip_repeat((Bound::Excluded(0), Bound::Included(2)));
+    assert_eq!(parser.parse(&mut input), Ok(()));
+    assert_eq!(input, "3");
+}
+
+#[test]
+#[should_panic = "empty repeat count range"]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_repeat_empty_range() {
+    let _ = digit()
+        .collect_repeat::<_, Vec<_>>(5..3)
+        .parse_partial("12");
+}
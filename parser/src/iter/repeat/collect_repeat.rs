@@ -0,0 +1,71 @@
+use super::*;
+
+pub struct CollectRepeat<P, O> {
+    parser: P,
+    bounds: Bounds,
+    _marker: PhantomData<O>,
+}
+
+impl<P, O> Copy for CollectRepeat<P, O> where P: Copy {}
+
+impl<P, O> Clone for CollectRepeat<P, O>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        CollectRepeat {
+            parser: self.parser.clone(),
+            bounds: self.bounds,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P, I, O> ParserOnce<I> for CollectRepeat<P, O>
+where
+    P: ParserMut<I>,
+    O: FromIterator<P::Output>,
+    I: Stream,
+{
+    type Output = O;
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<P, I, O> ParserMut<I> for CollectRepeat<P, O>
+where
+    P: ParserMut<I>,
+    O: FromIterator<P::Output>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parser
+            .by_mut_ref()
+            .repeat_mut(self.bounds, |iter| Some(iter.collect()))
+            .parse_mut(input)
+    }
+}
+
+impl<P, I, O> Parser<I> for CollectRepeat<P, O>
+where
+    P: Parser<I>,
+    O: FromIterator<P::Output>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parser
+            .by_ref()
+            .repeat(self.bounds, |iter| Some(iter.collect()))
+            .parse(input)
+    }
+}
+
+pub fn collect_repeat<P, O>(parser: P, count: impl RepeatCount) -> CollectRepeat<P, O> {
+    CollectRepeat {
+        parser,
+        bounds: count.bounds(),
+        _marker: PhantomData,
+    }
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct FoldRepeat<P, B, F> {
+    parser: P,
+    bounds: Bounds,
+    init: B,
+    f: F,
+}
+
+impl<P, B, F, I> ParserOnce<I> for FoldRepeat<P, B, F>
+where
+    P: ParserMut<I>,
+    F: FnMut(B, P::Output) -> B,
+    I: Stream,
+{
+    type Output = B;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let FoldRepeat {
+            parser,
+            bounds,
+            init,
+            f,
+        } = self;
+        parser
+            .repeat_once(bounds, |iter| Some(iter.fold(init, f)))
+            .parse_once(input)
+    }
+}
+
+impl<P, B, F, I> ParserMut<I> for FoldRepeat<P, B, F>
+where
+    P: ParserMut<I>,
+    B: Clone,
+    F: FnMut(B, P::Output) -> B,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let init = self.init.clone();
+        let f = &mut self.f;
+        self.parser
+            .by_mut_ref()
+            .repeat_once(self.bounds, |iter| Some(iter.fold(init, f)))
+            .parse_once(input)
+    }
+}
+
+impl<P, B, F, I> Parser<I> for FoldRepeat<P, B, F>
+where
+    P: Parser<I>,
+    B: Clone,
+    F: Fn(B, P::Output) -> B,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parser
+            .by_ref()
+            .repeat(self.bounds, |iter| {
+                Some(iter.fold(self.init.clone(), &self.f))
+            })
+            .parse(input)
+    }
+}
+
+pub fn fold_repeat<P, B, F>(
+    parser: P,
+    count: impl RepeatCount,
+    init: B,
+    f: F,
+) -> FoldRepeat<P, B, F> {
+    FoldRepeat {
+        parser,
+        bounds: count.bounds(),
+        init,
+        f,
+    }
+}
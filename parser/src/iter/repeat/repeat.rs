@@ -0,0 +1,109 @@
+use super::*;
+
+pub struct Iter<'a, P, I>
+where
+    I: Stream,
+{
+    parser: &'a P,
+    input: &'a mut I,
+    counter: Counter<'a, I>,
+}
+
+impl<P, I> Iterator for Iter<'_, P, I>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    type Item = P::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter.is_done() {
+            return None;
+        }
+        let result = self.parser.parse(self.input);
+        self.counter.next_item(result)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Repeat<P, F> {
+    parser: P,
+    bounds: Bounds,
+    f: F,
+}
+
+impl<P, F, I, O> ParserOnce<I> for Repeat<P, F>
+where
+    P: Parser<I>,
+    F: FnOnce(Iter<'_, P, I>) -> Option<O>,
+    I: Stream,
+{
+    type Output = O;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = Iter {
+            parser: &self.parser,
+            input,
+            counter: Counter {
+                bounds: self.bounds,
+                count: 0,
+                error: &mut error,
+            },
+        };
+        finish((self.f)(iter), error, position)
+    }
+}
+
+impl<P, F, I, O> ParserMut<I> for Repeat<P, F>
+where
+    P: Parser<I>,
+    F: FnMut(Iter<'_, P, I>) -> Option<O>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = Iter {
+            parser: &self.parser,
+            input,
+            counter: Counter {
+                bounds: self.bounds,
+                count: 0,
+                error: &mut error,
+            },
+        };
+        finish((self.f)(iter), error, position)
+    }
+}
+
+impl<P, F, I, O> Parser<I> for Repeat<P, F>
+where
+    P: Parser<I>,
+    F: Fn(Iter<'_, P, I>) -> Option<O>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = Iter {
+            parser: &self.parser,
+            input,
+            counter: Counter {
+                bounds: self.bounds,
+                count: 0,
+                error: &mut error,
+            },
+        };
+        finish((self.f)(iter), error, position)
+    }
+}
+
+pub fn repeat<P, F>(parser: P, count: impl RepeatCount, f: F) -> Repeat<P, F> {
+    Repeat {
+        parser,
+        bounds: count.bounds(),
+        f,
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+pub struct IterMut<'a, P, I>
+where
+    I: Stream,
+{
+    parser: &'a mut P,
+    input: &'a mut I,
+    counter: Counter<'a, I>,
+}
+
+impl<P, I> Iterator for IterMut<'_, P, I>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    type Item = P::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.counter.is_done() {
+            return None;
+        }
+        let result = self.parser.parse_mut(self.input);
+        self.counter.next_item(result)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct RepeatMut<P, F> {
+    parser: P,
+    bounds: Bounds,
+    f: F,
+}
+
+impl<P, F, I, O> ParserOnce<I> for RepeatMut<P, F>
+where
+    P: ParserMut<I>,
+    F: FnOnce(IterMut<'_, P, I>) -> Option<O>,
+    I: Stream,
+{
+    type Output = O;
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = IterMut {
+            parser: &mut self.parser,
+            input,
+            counter: Counter {
+                bounds: self.bounds,
+                count: 0,
+                error: &mut error,
+            },
+        };
+        finish((self.f)(iter), error, position)
+    }
+}
+
+impl<P, F, I, O> ParserMut<I> for RepeatMut<P, F>
+where
+    P: ParserMut<I>,
+    F: FnMut(IterMut<'_, P, I>) -> Option<O>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = IterMut {
+            parser: &mut self.parser,
+            input,
+            counter: Counter {
+                bounds: self.bounds,
+                count: 0,
+                error: &mut error,
+            },
+        };
+        finish((self.f)(iter), error, position)
+    }
+}
+
+pub fn repeat_mut<P, F>(parser: P, count: impl RepeatCount, f: F) -> RepeatMut<P, F> {
+    RepeatMut {
+        parser,
+        bounds: count.bounds(),
+        f,
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct SkipRepeat<P> {
+    parser: P,
+    bounds: Bounds,
+}
+
+impl<P, I> ParserOnce<I> for SkipRepeat<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    type Output = ();
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<P, I> ParserMut<I> for SkipRepeat<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parser
+            .by_mut_ref()
+            .repeat_mut(self.bounds, |iter| {
+                iter.for_each(drop);
+                Some(())
+            })
+            .parse_mut(input)
+    }
+}
+
+impl<P, I> Parser<I> for SkipRepeat<P>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parser
+            .by_ref()
+            .repeat(self.bounds, |iter| {
+                iter.for_each(drop);
+                Some(())
+            })
+            .parse(input)
+    }
+}
+
+pub fn skip_repeat<P>(parser: P, count: impl RepeatCount) -> SkipRepeat<P> {
+    SkipRepeat {
+        parser,
+        bounds: count.bounds(),
+    }
+}
@@ -1,5 +1,6 @@
 mod collect_sep_by;
 mod iter;
+#[allow(clippy::module_inception)]
 mod sep_by;
 mod sep_by_mut;
 mod skip_sep_by;
@@ -11,3 +12,11 @@ pub use iter::*;
 pub use sep_by::*;
 pub use sep_by_mut::*;
 pub use skip_sep_by::*;
+
+/// Whether a separator may, or must, follow the last element.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Trailing {
+    Forbidden,
+    Optional,
+    Required,
+}
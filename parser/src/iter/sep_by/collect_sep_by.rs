@@ -3,6 +3,7 @@ use super::*;
 pub struct CollectSepBy<P, Q, O> {
     parser: P,
     separator: Q,
+    trailing: Trailing,
     _marker: PhantomData<O>,
 }
 
@@ -22,6 +23,7 @@ where
         CollectSepBy {
             parser: self.parser.clone(),
             separator: self.separator.clone(),
+            trailing: self.trailing,
             _marker: PhantomData,
         }
     }
@@ -36,7 +38,7 @@ where
 {
     type Output = O;
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
@@ -48,11 +50,14 @@ where
     I: Stream,
     O: FromIterator<P::Output>,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.parser
-            .by_mut_ref()
-            .sep_by_mut(&mut self.separator, |iter| Some(iter.collect()))
-            .parse_mut(input)
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        sep_by_mut(
+            self.parser.by_mut_ref(),
+            &mut self.separator,
+            self.trailing,
+            |iter: IterMut<'_, _, _, I>| Some(iter.collect()),
+        )
+        .parse_mut(input)
     }
 }
 
@@ -63,18 +68,26 @@ where
     I: Stream,
     O: FromIterator<P::Output>,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        self.parser
-            .by_ref()
-            .sep_by(&self.separator, |iter| Some(iter.collect()))
-            .parse(input)
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        sep_by(
+            self.parser.by_ref(),
+            &self.separator,
+            self.trailing,
+            |iter: Iter<'_, _, _, I>| Some(iter.collect()),
+        )
+        .parse(input)
     }
 }
 
-pub fn collect_sep_by<P, Q, O>(parser: P, separator: Q) -> CollectSepBy<P, Q, O> {
+pub fn collect_sep_by<P, Q, O>(
+    parser: P,
+    separator: Q,
+    trailing: Trailing,
+) -> CollectSepBy<P, Q, O> {
     CollectSepBy {
         parser,
         separator,
+        trailing,
         _marker: PhantomData,
     }
 }
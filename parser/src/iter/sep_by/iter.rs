@@ -1,47 +1,123 @@
 use super::*;
 
-#[derive(Clone)]
-pub struct SepByIter<P, Q, I> {
+pub struct SepByIter<P, Q, I>
+where
+    I: Stream,
+{
     parser: P,
     separator: Q,
+    trailing: Trailing,
     start: bool,
     input: I,
+    error: Option<Error<I>>,
 }
 
-impl<P, Q, I> Iterator for SepByIter<P, Q, I>
+impl<P, Q, I> Clone for SepByIter<P, Q, I>
+where
+    P: Clone,
+    Q: Clone,
+    I: Stream,
+    I::Position: Clone,
+{
+    fn clone(&self) -> Self {
+        SepByIter {
+            parser: self.parser.clone(),
+            separator: self.separator.clone(),
+            trailing: self.trailing,
+            start: self.start,
+            input: self.input,
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<P, Q, I> SepByIter<P, Q, I>
+where
+    I: Stream,
+{
+    /// The input that hasn't been consumed yet. After an incomplete error,
+    /// this starts at the element (or separator) that couldn't be finished.
+    pub fn remaining(&self) -> I {
+        self.input
+    }
+
+    /// The fatal error that ended the iteration, if any, such as an incomplete
+    /// error at the end of a partial stream.
+    pub fn error(&self) -> Option<&Error<I>> {
+        self.error.as_ref()
+    }
+}
+
+impl<P, Q, I> SepByIter<P, Q, I>
 where
     P: ParserMut<I>,
     Q: ParserMut<I>,
     I: Stream,
 {
-    type Item = P::Output;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.start {
-            self.start = false;
-            self.parser.parse_mut(&mut self.input)
-        } else {
-            self.separator
+    fn parse_next(&mut self) -> Result<P::Output, Error<I>> {
+        let start = std::mem::replace(&mut self.start, false);
+        let input = &mut self.input;
+        match (self.trailing, start) {
+            (Trailing::Forbidden, true) | (Trailing::Optional, true) => {
+                self.parser.parse_mut(input)
+            }
+            (Trailing::Forbidden, false) => self
+                .separator
                 .by_mut_ref()
                 .followed_by(&mut self.parser)
                 .attempt()
                 .map_mut(|(_, o)| o)
-                .parse_mut(&mut self.input)
+                .parse_mut(input),
+            (Trailing::Optional, false) => {
+                self.separator.by_mut_ref().attempt().parse_mut(input)?;
+                self.parser.by_mut_ref().attempt().parse_mut(input)
+            }
+            (Trailing::Required, _) => self
+                .parser
+                .by_mut_ref()
+                .followed_by(&mut self.separator)
+                .attempt()
+                .map_mut(|(o, _)| o)
+                .parse_mut(input),
         }
     }
+}
+
+impl<P, Q, I> Iterator for SepByIter<P, Q, I>
+where
+    P: ParserMut<I>,
+    Q: ParserMut<I>,
+    I: Stream,
+{
+    type Item = P::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        let checkpoint = self.input;
+        let result = self.parse_next();
+        if result.as_ref().is_err_and(Error::is_incomplete) {
+            self.input = checkpoint;
+        }
+        next_item(result, &mut self.error)
+    }
 
     // TODO: implement `try_fold`
 }
 
-pub fn iter<P, Q, I>(parser: P, separator: Q, input: I) -> SepByIter<P, Q, I>
+pub fn iter<P, Q, I>(parser: P, separator: Q, trailing: Trailing, input: I) -> SepByIter<P, Q, I>
 where
     P: ParserMut<I>,
     Q: ParserMut<I>,
+    I: Stream,
 {
     SepByIter {
         parser,
         separator,
+        trailing,
         start: true,
         input,
+        error: None,
     }
 }
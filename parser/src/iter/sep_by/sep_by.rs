@@ -1,10 +1,15 @@
 use super::*;
 
-pub struct Iter<'a, P, Q, I> {
+pub struct Iter<'a, P, Q, I>
+where
+    I: Stream,
+{
     parser: &'a P,
     separator: &'a Q,
+    trailing: Trailing,
     start: bool,
     input: &'a mut I,
+    error: &'a mut Option<Error<I>>,
 }
 
 impl<P, Q, I> Iterator for Iter<'_, P, Q, I>
@@ -16,16 +21,29 @@ where
     type Item = P::Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.start {
-            self.start = false;
-            self.parser.parse_mut(&mut self.input)
-        } else {
-            self.separator
+        let start = std::mem::replace(&mut self.start, false);
+        let result = match (self.trailing, start) {
+            (Trailing::Forbidden, true) | (Trailing::Optional, true) => {
+                self.parser.parse(self.input)
+            }
+            (Trailing::Forbidden, false) => self
+                .separator
                 .followed_by(self.parser)
                 .attempt()
                 .map(|(_, o)| o)
-                .parse(&mut self.input)
-        }
+                .parse(self.input),
+            (Trailing::Optional, false) => {
+                next_item(self.separator.attempt().parse(self.input), self.error)?;
+                self.parser.attempt().parse(self.input)
+            }
+            (Trailing::Required, _) => self
+                .parser
+                .followed_by(self.separator)
+                .attempt()
+                .map(|(o, _)| o)
+                .parse(self.input),
+        };
+        next_item(result, self.error)
     }
 
     // TODO: implement `try_fold`
@@ -35,6 +53,7 @@ where
 pub struct SepBy<P, Q, F> {
     parser: P,
     separator: Q,
+    trailing: Trailing,
     f: F,
 }
 
@@ -43,16 +62,22 @@ where
     P: Parser<I>,
     Q: Parser<I>,
     F: FnOnce(Iter<'_, P, Q, I>) -> Option<O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        (self.f)(Iter {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = Iter {
             parser: &self.parser,
             separator: &self.separator,
+            trailing: self.trailing,
             start: true,
             input,
-        })
+            error: &mut error,
+        };
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -61,14 +86,20 @@ where
     P: Parser<I>,
     Q: Parser<I>,
     F: FnMut(Iter<'_, P, Q, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        (self.f)(Iter {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = Iter {
             parser: &self.parser,
             separator: &self.separator,
+            trailing: self.trailing,
             start: true,
             input,
-        })
+            error: &mut error,
+        };
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -77,22 +108,28 @@ where
     P: Parser<I>,
     Q: Parser<I>,
     F: Fn(Iter<'_, P, Q, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
         let iter = Iter {
             parser: &self.parser,
             separator: &self.separator,
+            trailing: self.trailing,
             start: true,
             input,
+            error: &mut error,
         };
-        (self.f)(iter)
+        finish((self.f)(iter), error, position)
     }
 }
 
-pub fn sep_by<P, Q, F>(parser: P, separator: Q, f: F) -> SepBy<P, Q, F> {
+pub fn sep_by<P, Q, F>(parser: P, separator: Q, trailing: Trailing, f: F) -> SepBy<P, Q, F> {
     SepBy {
         parser,
         separator,
+        trailing,
         f,
     }
 }
@@ -1,10 +1,15 @@
 use super::*;
 
-pub struct IterMut<'a, P, Q, I> {
+pub struct IterMut<'a, P, Q, I>
+where
+    I: Stream,
+{
     parser: &'a mut P,
     separator: &'a mut Q,
+    trailing: Trailing,
     start: bool,
     input: &'a mut I,
+    error: &'a mut Option<Error<I>>,
 }
 
 impl<P, Q, I> Iterator for IterMut<'_, P, Q, I>
@@ -16,16 +21,30 @@ where
     type Item = P::Output;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.start {
-            self.start = false;
-            self.parser.parse_mut(&mut self.input)
-        } else {
-            self.separator
+        let start = std::mem::replace(&mut self.start, false);
+        let result = match (self.trailing, start) {
+            (Trailing::Forbidden, true) | (Trailing::Optional, true) => {
+                self.parser.parse_mut(self.input)
+            }
+            (Trailing::Forbidden, false) => self
+                .separator
                 .followed_by(&mut self.parser)
                 .attempt()
                 .map_mut(|(_, o)| o)
-                .parse_mut(&mut self.input)
-        }
+                .parse_mut(self.input),
+            (Trailing::Optional, false) => {
+                let separator = self.separator.attempt().parse_mut(self.input);
+                next_item(separator, self.error)?;
+                self.parser.attempt().parse_mut(self.input)
+            }
+            (Trailing::Required, _) => self
+                .parser
+                .followed_by(&mut self.separator)
+                .attempt()
+                .map_mut(|(o, _)| o)
+                .parse_mut(self.input),
+        };
+        next_item(result, self.error)
     }
 
     // TODO: implement `try_fold`
@@ -35,6 +54,7 @@ where
 pub struct SepByMut<P, Q, F> {
     parser: P,
     separator: Q,
+    trailing: Trailing,
     f: F,
 }
 
@@ -43,16 +63,22 @@ where
     P: ParserMut<I>,
     Q: ParserMut<I>,
     F: FnOnce(IterMut<'_, P, Q, I>) -> Option<O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
-        (self.f)(IterMut {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = IterMut {
             parser: &mut self.parser,
             separator: &mut self.separator,
+            trailing: self.trailing,
             start: true,
             input,
-        })
+            error: &mut error,
+        };
+        finish((self.f)(iter), error, position)
     }
 }
 
@@ -61,21 +87,28 @@ where
     P: ParserMut<I>,
     Q: ParserMut<I>,
     F: FnMut(IterMut<'_, P, Q, I>) -> Option<O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        (self.f)(IterMut {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let mut error = None;
+        let iter = IterMut {
             parser: &mut self.parser,
             separator: &mut self.separator,
+            trailing: self.trailing,
             start: true,
             input,
-        })
+            error: &mut error,
+        };
+        finish((self.f)(iter), error, position)
     }
 }
 
-pub fn sep_by_mut<P, Q, F>(parser: P, separator: Q, f: F) -> SepByMut<P, Q, F> {
+pub fn sep_by_mut<P, Q, F>(parser: P, separator: Q, trailing: Trailing, f: F) -> SepByMut<P, Q, F> {
     SepByMut {
         parser,
         separator,
+        trailing,
         f,
     }
 }
@@ -4,6 +4,7 @@ use super::*;
 pub struct SkipSepBy<P, Q> {
     parser: P,
     separator: Q,
+    trailing: Trailing,
 }
 
 impl<P, Q, I> ParserOnce<I> for SkipSepBy<P, Q>
@@ -14,7 +15,7 @@ where
 {
     type Output = ();
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
@@ -25,14 +26,17 @@ where
     Q: ParserMut<I>,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.parser
-            .by_mut_ref()
-            .sep_by_mut(&mut self.separator, |iter| {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        sep_by_mut(
+            self.parser.by_mut_ref(),
+            &mut self.separator,
+            self.trailing,
+            |iter: IterMut<'_, _, _, I>| {
                 iter.for_each(drop);
                 Some(())
-            })
-            .parse_mut(input)
+            },
+        )
+        .parse_mut(input)
     }
 }
 
@@ -42,17 +46,24 @@ where
     Q: Parser<I>,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        self.parser
-            .by_ref()
-            .sep_by(&self.separator, |iter| {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        sep_by(
+            self.parser.by_ref(),
+            &self.separator,
+            self.trailing,
+            |iter: Iter<'_, _, _, I>| {
                 iter.for_each(drop);
                 Some(())
-            })
-            .parse(input)
+            },
+        )
+        .parse(input)
     }
 }
 
-pub fn skip_sep_by<P, Q>(parser: P, separator: Q) -> SkipSepBy<P, Q> {
-    SkipSepBy { parser, separator }
+pub fn skip_sep_by<P, Q>(parser: P, separator: Q, trailing: Trailing) -> SkipSepBy<P, Q> {
+    SkipSepBy {
+        parser,
+        separator,
+        trailing,
+    }
 }
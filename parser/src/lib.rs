@@ -1,14 +1,18 @@
 #![feature(try_trait_v2)]
 
+mod error;
 mod iter;
 mod parser;
+mod report;
 mod stream;
 mod tuple;
 
 pub use self::parser::*;
-use iter::{many, many1, sep_by};
+pub use error::{Collector, Error, Info};
+use iter::{many, many1, repeat, sep_by};
 pub use parser_macros::opaque;
-pub use stream::Stream;
+pub use report::Report;
+pub use stream::{Bytes, Located, Location, Partial, ReadBuffer, ReadStream, Stream};
 pub use tuple::{chain, choice};
 
 use std::iter::FromIterator;
@@ -22,12 +26,14 @@ pub mod prelude {
             Parser, ParserMut, ParserOnce,
         },
         tuple::{chain, choice},
+        Error, Info,
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::borrow::Cow;
     use std::fmt::Debug;
 
     fn assert_parse<P, I>(parser: P, mut input: I, output: Option<P::Output>, remainder: I)
@@ -36,7 +42,7 @@ mod tests {
         P::Output: Debug + PartialEq,
         I: Stream + Debug + PartialEq,
     {
-        assert_eq!(parser.parse(&mut input), output);
+        assert_eq!(parser.parse(&mut input).ok(), output);
         assert_eq!(input, remainder);
     }
 
@@ -52,6 +58,113 @@ mod tests {
         assert_parse(choice((string("aa"), string("ab"))), "abc", None, "bc");
     }
 
+    #[test]
+    fn test_or_expected() {
+        let error = choice((token('a'), token('b'), token('c')))
+            .parse_to_end("d")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected 'd', expected 'a', 'b' or 'c'"
+        );
+
+        let error = choice((string("ab").attempt(), string("x")))
+            .parse_to_end("ac")
+            .unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'c', expected 'b'");
+    }
+
+    #[test]
+    fn test_label() {
+        let identifier = satisfy(char::is_alphabetic)
+            .skip_many1()
+            .recognize()
+            .label("identifier");
+        let error = identifier.parse_to_end("1").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected '1', expected identifier");
+
+        let error = chain((token('a'), token('b')))
+            .label("ab")
+            .parse_to_end("ac")
+            .unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'c', expected 'b'");
+    }
+
+    #[test]
+    fn test_cut() {
+        let array = || {
+            super::u32()
+                .collect_sep_by::<_, Vec<_>>(token(','))
+                .between(token('['), token(']').cut())
+        };
+
+        let error = array()
+            .collect_many::<Vec<_>>()
+            .parse_to_end("[1][2")
+            .unwrap_err();
+        assert!(error.is_fatal());
+        assert_eq!(error.to_string(), "unexpected end of input, expected ']'");
+
+        let error = choice((array().map(|_| ()), token('[').map(drop)))
+            .parse_to_end("[1")
+            .unwrap_err();
+        assert!(error.is_fatal());
+
+        assert_parse(
+            choice((string("ab").cut(), string("c"))).optional(),
+            "c",
+            None,
+            "c",
+        );
+    }
+
+    #[test]
+    fn test_recover() {
+        let errors = Collector::new();
+        let output = super::u32()
+            .recover_with(skip_until(token(';')).map(|()| 0), &errors)
+            .collect_sep_by::<_, Vec<_>>(token(';'))
+            .parse_to_end("1;x y;3");
+        assert_eq!(output, Ok(vec![1, 0, 3]));
+        let errors = errors.into_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "unexpected 'x', expected digit");
+
+        let errors = Collector::new();
+        let output = super::u32()
+            .collect_sep_by::<_, Vec<_>>(token(','))
+            .between(token('['), token(']'))
+            .recover_with(skip_balanced('[', ']').map(|()| Vec::new()), &errors)
+            .collect_sep_by::<_, Vec<_>>(token(';'))
+            .parse_to_end("[1,2];[3,x,[4]];[5]");
+        assert_eq!(output, Ok(vec![vec![1, 2], vec![], vec![5]]));
+        let errors = errors.into_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "unexpected ',', expected ']'");
+
+        let errors = Collector::new();
+        let output = chain((super::u32(), token(';')))
+            .map(|(n, _)| n)
+            .recover_with(
+                chain((skip_until(token(';')), token(';'))).map(|_| 0),
+                &errors,
+            )
+            .collect_many::<Vec<_>>()
+            .parse_to_end("1;x;2;");
+        assert_eq!(output, Ok(vec![1, 0, 2]));
+        let errors = errors.into_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "unexpected 'x', expected digit");
+
+        let errors = Collector::new();
+        let output = super::u32()
+            .recover_with(insert_with(|| 0), &errors)
+            .between(token('('), token(')'))
+            .parse_to_end("()");
+        assert_eq!(output, Ok(0));
+        assert_eq!(errors.len(), 1);
+    }
+
     #[test]
     fn test_attempt() {
         assert_parse(
@@ -70,6 +183,85 @@ mod tests {
             Some((123, "ab")),
             "c",
         );
+
+        let digit = || satisfy_map(|c: char| c.to_digit(10));
+        let mut input = "0123456789012345x";
+        let (a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p) = chain((
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+        ))
+        .parse(&mut input)
+        .unwrap();
+        assert_eq!((a, b, c, d, e, f, g, h), (0, 1, 2, 3, 4, 5, 6, 7));
+        assert_eq!((i, j, k, l, m, n, o, p), (8, 9, 0, 1, 2, 3, 4, 5));
+        assert_eq!(input, "x");
+    }
+
+    #[test]
+    fn test_choice() {
+        let letter = |c| token(c).map(move |_| c);
+        let parser = choice((
+            letter('a'),
+            letter('b'),
+            letter('c'),
+            letter('d'),
+            letter('e'),
+            letter('f'),
+            letter('g'),
+            letter('h'),
+            letter('i'),
+            letter('j'),
+            letter('k'),
+            letter('l'),
+            letter('m'),
+            letter('n'),
+            letter('o'),
+            letter('p'),
+        ));
+        assert_parse(&parser, "p", Some('p'), "");
+        assert_parse(&parser, "a", Some('a'), "");
+        let error = parser.parse_to_end("q").unwrap_err();
+        assert_eq!(error.expected().len(), 16);
+
+        let mut count = 0;
+        let mut parser = choice((
+            letter('a'),
+            letter('b'),
+            letter('c'),
+            letter('d'),
+            letter('e'),
+            letter('f'),
+            letter('g'),
+            letter('h'),
+            letter('i'),
+            letter('j'),
+            letter('k'),
+            letter('l'),
+            letter('m'),
+            letter('n'),
+            letter('o'),
+            from_fn_mut(|input: &mut &str| {
+                count += 1;
+                token('p').parse(input)
+            }),
+        ));
+        assert_eq!(parser.parse_mut(&mut "p"), Ok('p'));
+        assert_eq!(parser.parse_mut(&mut "p"), Ok('p'));
+        assert_eq!(count, 2);
     }
 
     #[test]
@@ -84,6 +276,74 @@ mod tests {
         assert_parse(super::i32(), "abc", None, "abc");
     }
 
+    #[test]
+    fn test_integer_radix() {
+        assert_parse(hex(), "0x1F_ff,", Some(0x1fffu32), ",");
+        assert_parse(hex(), "ff", Some(0xffu32), "");
+        assert_parse(hex(), "0xg", Some(0u32), "xg");
+        assert_parse(hex(), "-0x80", Some(-128i8), "");
+        assert_parse(octal(), "0o17", Some(15u8), "");
+        assert_parse(binary(), "0b1010_1010", Some(0b1010_1010u8), "");
+        assert_parse(binary(), "2", None::<u8>, "2");
+        assert_parse(radix(36), "zz", Some(36 * 36 - 1u32), "");
+        assert_parse(super::i32(), "+42", Some(42), "");
+        assert_parse(super::u32(), "+42", None, "+42");
+        assert_parse(super::i8(), "-128", Some(-128), "");
+        assert_parse(super::u32(), "1_000", Some(1), "_000");
+        assert_parse(decimal(), "1_000__000", Some(1_000_000u32), "");
+        assert_parse(decimal(), "1_,", Some(1u32), "_,");
+        assert_parse(hex(), "12_abc_", Some(0x12abcu32), "_");
+
+        let error = super::u8().parse_to_end("256").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected integer overflow");
+        let error = hex::<u8, _>().parse_to_end("x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected 'x', expected hexadecimal digit"
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_parse(super::f64(), "1.5e3,", Some(1500.0), ",");
+        assert_parse(super::f64(), "+2E-1", Some(0.2), "");
+        assert_parse(super::f64(), "-0.25", Some(-0.25), "");
+        assert_parse(super::f64(), "1.max(2)", Some(1.0), ".max(2)");
+        assert_parse(super::f64(), "1e", Some(1.0), "e");
+        assert_parse(super::f64(), "-inf", Some(f64::NEG_INFINITY), "");
+        assert_parse(super::f64(), "infinity", Some(f64::INFINITY), "");
+        assert!(super::f64().parse_to_end("NaN").unwrap().is_nan());
+        assert_parse(super::f32(), "3.25", Some(3.25), "");
+        assert_parse(super::f64(), ".5", None, ".5");
+        let error = super::f64().parse_to_end("-").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected end of input, expected digit, 'i' or 'N'"
+        );
+
+        assert_parse(super::json_f64(), "-12.5e+2]", Some(-1250.0), "]");
+        assert_parse(super::json_f64(), "0.5", Some(0.5), "");
+        assert_parse(super::json_f64(), "01", Some(0.0), "1");
+        assert_parse(super::json_f64(), "+1", None, "+1");
+        assert_parse(super::json_f64(), "inf", None, "inf");
+        assert_parse(super::json_f32(), "1.5", Some(1.5), "");
+    }
+
+    #[test]
+    fn test_error() {
+        let input = "[1,2;";
+        let error = super::u32()
+            .collect_sep_by::<_, Vec<_>>(token(','))
+            .between(token('['), token(']'))
+            .parse_to_end(input)
+            .unwrap_err();
+        assert_eq!(*error.position(), input[4..].as_ptr());
+        assert_eq!(error.to_string(), "unexpected ';', expected ']'");
+
+        let error = string("ab").parse_to_end("abc").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'c', expected end of input");
+    }
+
     #[test]
     fn test_many() {
         let mut input = "abcabcabcde";
@@ -103,10 +363,49 @@ mod tests {
             .recognize()
             .collect_sep_by(super::u32())
             .parse(&mut input);
-        assert_eq!(vec, Some(vec!["abc", "abc"]));
+        assert_eq!(vec, Ok(vec!["abc", "abc"]));
         assert_eq!(input, "26abde");
     }
 
+    #[test]
+    fn test_sep_end_by() {
+        let array = || {
+            super::u32()
+                .collect_sep_end_by::<_, Vec<_>>(token(','))
+                .between(token('['), token(']'))
+        };
+        assert_eq!(array().parse_to_end("[1,2,]"), Ok(vec![1, 2]));
+        assert_eq!(array().parse_to_end("[1,2]"), Ok(vec![1, 2]));
+        assert_eq!(array().parse_to_end("[]"), Ok(vec![]));
+        assert!(array().parse_to_end("[,]").is_err());
+        assert!(array().parse_to_end("[1,,]").is_err());
+
+        let mut input = "a;b;c";
+        let count = satisfy(char::is_alphabetic).sep_end_by(token(';'), |iter| Some(iter.count()));
+        assert_eq!(count.parse(&mut input), Ok(3));
+
+        let items: Vec<_> = super::u32().iter_sep_end_by(token(','), "1,2,x").collect();
+        assert_eq!(items, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_end_by() {
+        let statements = super::u32().collect_end_by::<_, Vec<_>>(token(';'));
+        assert_eq!(statements.parse_to_end("1;2;"), Ok(vec![1, 2]));
+        assert_eq!(statements.parse_to_end(""), Ok(vec![]));
+        assert!(statements.parse_to_end("1;2").is_err());
+
+        let mut input = "1;2;3";
+        assert_eq!(
+            super::u32().skip_end_by(token(';')).parse(&mut input),
+            Ok(())
+        );
+        assert_eq!(input, "3");
+
+        let items: Vec<_> = super::u32().iter_end_by(token(';'), "1;2;3").collect();
+        assert_eq!(items, vec![1, 2]);
+    }
+
     #[test]
     fn test_iter_many() {
         let input = "abcabcabcde";
@@ -118,7 +417,7 @@ mod tests {
 
     #[test]
     fn test_recursive() {
-        #[allow(unused)]
+        #[allow(unused, clippy::upper_case_acronyms)]
         enum JSON {
             Array(Vec<JSON>),
             Object(Vec<(String, JSON)>),
@@ -138,11 +437,10 @@ mod tests {
 
         #[allow(unused)]
         fn string<'a>() -> impl Parser<&'a str, Output = String> {
-            satisfy(char::is_alphabetic)
-                .skip_many1()
-                .recognize()
-                .from_str()
-                .between(token('\"'), token('\"'))
+            escaped_string('"', '\\')
+                .escapes(&[('"', '"'), ('\\', '\\'), ('n', '\n')])
+                .unicode('u')
+                .map(Cow::into_owned)
         }
 
         #[allow(unused)]
@@ -159,5 +457,11 @@ mod tests {
                 .collect_sep_by(token(','))
                 .between(token('{'), token('}'))
         }
+
+        let error = json().parse_to_end("x").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "unexpected 'x', expected digit, '\"', '[' or '{'"
+        );
     }
 }
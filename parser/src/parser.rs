@@ -1,48 +1,72 @@
 mod and_then;
 mod attempt;
 mod between;
+pub mod byte;
+mod class;
+mod cut;
+mod escaped_string;
 mod flat_map;
 mod followed_by;
 mod from_fn;
 mod from_str;
+mod label;
+mod literals;
+mod lookahead;
 mod map;
+mod not_followed_by;
 mod num;
 mod optional;
 mod or;
+mod precedence;
 mod recognize;
+mod recover;
 mod satisfy;
 mod satisfy_map;
+mod string_no_case;
+mod take_until;
+mod take_while;
 mod tokens;
 
 use super::*;
 
+pub use class::*;
+pub use escaped_string::escaped_string;
 pub use from_fn::{from_fn, from_fn_mut, from_fn_once};
+pub use literals::{literal_map, literals};
+pub use lookahead::lookahead;
+pub use not_followed_by::not_followed_by;
 pub use num::*;
+pub use precedence::{precedence, Assoc};
+pub use recover::{insert_with, skip_balanced, skip_until};
 pub use satisfy::satisfy;
 pub use satisfy_map::{satisfy_map, satisfy_map_mut, satisfy_map_once};
+pub use string_no_case::{keyword, keyword_no_case, string_no_case, string_no_case_unicode};
+pub use take_until::take_until;
+pub use take_while::{take_while, take_while1, take_while_m_n};
 pub use tokens::tokens;
 
-pub trait ParserOnce<Input>: Sized {
+pub trait ParserOnce<Input>: Sized
+where
+    Input: Stream,
+{
     type Output;
 
-    fn parse_once(self, input: &mut Input) -> Option<Self::Output>;
+    fn parse_once(self, input: &mut Input) -> Result<Self::Output, Error<Input>>;
 
-    fn parse_once_and_check_consumed(self, input: &mut Input) -> (Option<Self::Output>, bool)
-    where
-        Input: Stream,
-    {
+    fn parse_once_and_check_consumed(
+        self,
+        input: &mut Input,
+    ) -> (Result<Self::Output, Error<Input>>, bool) {
+        let position = input.position();
         let output = self.parse_once(input);
-        (output, input.is_empty())
+        (output, input.position() != position)
     }
 
-    fn parse_partial(self, mut input: Input) -> Option<Self::Output> {
+    fn parse_partial(self, mut input: Input) -> Result<Self::Output, Error<Input>> {
         self.parse_once(&mut input)
     }
 
-    fn parse_to_end(self, input: Input) -> Option<Self::Output>
-    where
-        Input: Stream,
-    {
+    fn parse_to_end(self, input: Input) -> Result<Self::Output, Error<Input>> {
         self.followed_by(eof())
             .map_once(|(o, ())| o)
             .parse_partial(input)
@@ -78,10 +102,13 @@ pub trait ParserOnce<Input>: Sized {
         optional::optional(self)
     }
 
+    /// Returns the range of input consumed by this parser instead of its
+    /// output.
     fn recognize(self) -> recognize::Recognize<Self> {
         recognize::recognize(self)
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn from_str<'a, O>(self) -> from_str::FromStr<Self, O>
     where
         Self: ParserOnce<Input, Output = &'a str>,
@@ -89,10 +116,29 @@ pub trait ParserOnce<Input>: Sized {
         from_str::from_str(self)
     }
 
+    fn label(self, label: &'static str) -> label::Label<Self> {
+        label::label(self, label)
+    }
+
+    fn recover_with<R>(
+        self,
+        strategy: R,
+        collector: &Collector<Input>,
+    ) -> recover::Recover<'_, Self, R, Input>
+    where
+        R: ParserOnce<Input, Output = Self::Output>,
+    {
+        recover::recover(self, strategy, collector)
+    }
+
     fn attempt(self) -> attempt::Attempt<Self> {
         attempt::attempt(self)
     }
 
+    fn cut(self) -> cut::Cut<Self> {
+        cut::cut(self)
+    }
+
     fn between<L, R>(self, left: L, right: R) -> between::Between<Self, L, R>
     where
         L: Parser<Input>,
@@ -116,13 +162,16 @@ pub trait ParserOnce<Input>: Sized {
     }
 }
 
-pub trait ParserMut<Input>: ParserOnce<Input> {
-    fn parse_mut(&mut self, input: &mut Input) -> Option<Self::Output>;
+pub trait ParserMut<Input>: ParserOnce<Input>
+where
+    Input: Stream,
+{
+    fn parse_mut(&mut self, input: &mut Input) -> Result<Self::Output, Error<Input>>;
 
-    fn parse_mut_and_check_consumed(&mut self, input: &mut Input) -> (Option<Self::Output>, bool)
-    where
-        Input: Stream,
-    {
+    fn parse_mut_and_check_consumed(
+        &mut self,
+        input: &mut Input,
+    ) -> (Result<Self::Output, Error<Input>>, bool) {
         let position = input.position();
         let output = self.parse_mut(input);
         (output, input.position() != position)
@@ -183,6 +232,16 @@ pub trait ParserMut<Input>: ParserOnce<Input> {
         many::collect_many(self)
     }
 
+    /// Parses `self` until `end` succeeds, and returns the collected outputs
+    /// together with the output of `end`.
+    fn many_till<P, I>(self, end: P) -> many::ManyTill<Self, P, I>
+    where
+        P: ParserMut<Input>,
+        I: FromIterator<Self::Output>,
+    {
+        many::many_till(self, end)
+    }
+
     // TODO: merge with many1_mut
     fn many1_once<F, O>(self, f: F) -> many1::Many1Mut<Self, F>
     where
@@ -210,13 +269,74 @@ pub trait ParserMut<Input>: ParserOnce<Input> {
         many1::collect_many1(self)
     }
 
+    fn repeat_once<R, F, O>(self, count: R, f: F) -> repeat::RepeatMut<Self, F>
+    where
+        R: repeat::RepeatCount,
+        F: FnOnce(repeat::IterMut<'_, Self, Input>) -> Option<O>,
+    {
+        repeat::repeat_mut(self, count, f)
+    }
+
+    /// Like `many_mut`, but the iterator stops after the maximum number of
+    /// items given by `count`, and parsing fails if an item fails before the
+    /// minimum is reached.
+    fn repeat_mut<R, F, O>(self, count: R, f: F) -> repeat::RepeatMut<Self, F>
+    where
+        R: repeat::RepeatCount,
+        F: FnMut(repeat::IterMut<'_, Self, Input>) -> Option<O>,
+    {
+        repeat::repeat_mut(self, count, f)
+    }
+
+    fn skip_repeat<R>(self, count: R) -> repeat::SkipRepeat<Self>
+    where
+        R: repeat::RepeatCount,
+    {
+        repeat::skip_repeat(self, count)
+    }
+
+    fn collect_repeat<R, I>(self, count: R) -> repeat::CollectRepeat<Self, I>
+    where
+        R: repeat::RepeatCount,
+        I: FromIterator<Self::Output>,
+    {
+        repeat::collect_repeat(self, count)
+    }
+
+    fn fold_repeat_mut<R, B, F>(self, count: R, init: B, f: F) -> repeat::FoldRepeat<Self, B, F>
+    where
+        R: repeat::RepeatCount,
+        F: FnMut(B, Self::Output) -> B,
+    {
+        repeat::fold_repeat(self, count, init, f)
+    }
+
+    /// Parses one or more occurrences of `self` separated by `op`, and folds
+    /// them from the left with the functions that `op` produces.
+    fn chain_left<Op>(self, op: Op) -> many1::ChainLeft<Self, Op>
+    where
+        Op: ParserMut<Input>,
+        Op::Output: FnOnce(Self::Output, Self::Output) -> Self::Output,
+    {
+        many1::chain_left(self, op)
+    }
+
+    /// Like `chain_left`, but folds from the right.
+    fn chain_right<Op>(self, op: Op) -> many1::ChainRight<Self, Op>
+    where
+        Op: ParserMut<Input>,
+        Op::Output: FnOnce(Self::Output, Self::Output) -> Self::Output,
+    {
+        many1::chain_right(self, op)
+    }
+
     // TODO: merge with `sep_by_mut`
     fn sep_by_once<P, F, O>(self, separator: P, f: F) -> sep_by::SepByMut<Self, P, F>
     where
         P: ParserMut<Input>,
         F: FnOnce(sep_by::IterMut<'_, Self, P, Input>) -> Option<O>,
     {
-        sep_by::sep_by_mut(self, separator, f)
+        sep_by::sep_by_mut(self, separator, sep_by::Trailing::Forbidden, f)
     }
 
     // TODO: maybe somehow combine this with `Parser::sep_by`
@@ -225,21 +345,21 @@ pub trait ParserMut<Input>: ParserOnce<Input> {
         P: ParserMut<Input>,
         F: FnMut(sep_by::IterMut<'_, Self, P, Input>) -> Option<O>,
     {
-        sep_by::sep_by_mut(self, separator, f)
+        sep_by::sep_by_mut(self, separator, sep_by::Trailing::Forbidden, f)
     }
 
     fn iter_sep_by<P>(self, separator: P, input: Input) -> sep_by::SepByIter<Self, P, Input>
     where
         P: Parser<Input>,
     {
-        sep_by::iter(self, separator, input)
+        sep_by::iter(self, separator, sep_by::Trailing::Forbidden, input)
     }
 
     fn skip_sep_by<P>(self, separator: P) -> sep_by::SkipSepBy<Self, P>
     where
         P: Parser<Input>,
     {
-        sep_by::skip_sep_by(self, separator)
+        sep_by::skip_sep_by(self, separator, sep_by::Trailing::Forbidden)
     }
 
     fn collect_sep_by<P, I>(self, separator: P) -> sep_by::CollectSepBy<Self, P, I>
@@ -247,7 +367,69 @@ pub trait ParserMut<Input>: ParserOnce<Input> {
         P: Parser<Input>,
         I: FromIterator<Self::Output>,
     {
-        sep_by::collect_sep_by(self, separator)
+        sep_by::collect_sep_by(self, separator, sep_by::Trailing::Forbidden)
+    }
+
+    /// Like `sep_by_mut`, but allows a separator after the last element.
+    fn sep_end_by_mut<P, F, O>(self, separator: P, f: F) -> sep_by::SepByMut<Self, P, F>
+    where
+        P: ParserMut<Input>,
+        F: FnMut(sep_by::IterMut<'_, Self, P, Input>) -> Option<O>,
+    {
+        sep_by::sep_by_mut(self, separator, sep_by::Trailing::Optional, f)
+    }
+
+    fn iter_sep_end_by<P>(self, separator: P, input: Input) -> sep_by::SepByIter<Self, P, Input>
+    where
+        P: Parser<Input>,
+    {
+        sep_by::iter(self, separator, sep_by::Trailing::Optional, input)
+    }
+
+    fn skip_sep_end_by<P>(self, separator: P) -> sep_by::SkipSepBy<Self, P>
+    where
+        P: Parser<Input>,
+    {
+        sep_by::skip_sep_by(self, separator, sep_by::Trailing::Optional)
+    }
+
+    fn collect_sep_end_by<P, I>(self, separator: P) -> sep_by::CollectSepBy<Self, P, I>
+    where
+        P: Parser<Input>,
+        I: FromIterator<Self::Output>,
+    {
+        sep_by::collect_sep_by(self, separator, sep_by::Trailing::Optional)
+    }
+
+    /// Like `sep_by_mut`, but requires a separator after every element.
+    fn end_by_mut<P, F, O>(self, separator: P, f: F) -> sep_by::SepByMut<Self, P, F>
+    where
+        P: ParserMut<Input>,
+        F: FnMut(sep_by::IterMut<'_, Self, P, Input>) -> Option<O>,
+    {
+        sep_by::sep_by_mut(self, separator, sep_by::Trailing::Required, f)
+    }
+
+    fn iter_end_by<P>(self, separator: P, input: Input) -> sep_by::SepByIter<Self, P, Input>
+    where
+        P: Parser<Input>,
+    {
+        sep_by::iter(self, separator, sep_by::Trailing::Required, input)
+    }
+
+    fn skip_end_by<P>(self, separator: P) -> sep_by::SkipSepBy<Self, P>
+    where
+        P: Parser<Input>,
+    {
+        sep_by::skip_sep_by(self, separator, sep_by::Trailing::Required)
+    }
+
+    fn collect_end_by<P, I>(self, separator: P) -> sep_by::CollectSepBy<Self, P, I>
+    where
+        P: Parser<Input>,
+        I: FromIterator<Self::Output>,
+    {
+        sep_by::collect_sep_by(self, separator, sep_by::Trailing::Required)
     }
 
     fn by_mut_ref(&mut self) -> &mut Self {
@@ -255,13 +437,16 @@ pub trait ParserMut<Input>: ParserOnce<Input> {
     }
 }
 
-pub trait Parser<Input>: ParserMut<Input> {
-    fn parse(&self, input: &mut Input) -> Option<Self::Output>;
+pub trait Parser<Input>: ParserMut<Input>
+where
+    Input: Stream,
+{
+    fn parse(&self, input: &mut Input) -> Result<Self::Output, Error<Input>>;
 
-    fn parse_and_check_consumed(&self, input: &mut Input) -> (Option<Self::Output>, bool)
-    where
-        Input: Stream,
-    {
+    fn parse_and_check_consumed(
+        &self,
+        input: &mut Input,
+    ) -> (Result<Self::Output, Error<Input>>, bool) {
         let position = input.position();
         let output = self.parse(input);
         (output, input.position() != position)
@@ -303,12 +488,44 @@ pub trait Parser<Input>: ParserMut<Input> {
         many1::many1(self, f)
     }
 
+    fn repeat<R, F, O>(self, count: R, f: F) -> repeat::Repeat<Self, F>
+    where
+        R: repeat::RepeatCount,
+        F: Fn(repeat::Iter<'_, Self, Input>) -> Option<O>,
+    {
+        repeat::repeat(self, count, f)
+    }
+
+    fn fold_repeat<R, B, F>(self, count: R, init: B, f: F) -> repeat::FoldRepeat<Self, B, F>
+    where
+        R: repeat::RepeatCount,
+        F: Fn(B, Self::Output) -> B,
+    {
+        repeat::fold_repeat(self, count, init, f)
+    }
+
     fn sep_by<P, F, O>(self, separator: P, f: F) -> sep_by::SepBy<Self, P, F>
     where
         P: Parser<Input>,
         F: Fn(sep_by::Iter<'_, Self, P, Input>) -> Option<O>,
     {
-        sep_by::sep_by(self, separator, f)
+        sep_by::sep_by(self, separator, sep_by::Trailing::Forbidden, f)
+    }
+
+    fn sep_end_by<P, F, O>(self, separator: P, f: F) -> sep_by::SepBy<Self, P, F>
+    where
+        P: Parser<Input>,
+        F: Fn(sep_by::Iter<'_, Self, P, Input>) -> Option<O>,
+    {
+        sep_by::sep_by(self, separator, sep_by::Trailing::Optional, f)
+    }
+
+    fn end_by<P, F, O>(self, separator: P, f: F) -> sep_by::SepBy<Self, P, F>
+    where
+        P: Parser<Input>,
+        F: Fn(sep_by::Iter<'_, Self, P, Input>) -> Option<O>,
+    {
+        sep_by::sep_by(self, separator, sep_by::Trailing::Required, f)
     }
 
     fn by_ref(&self) -> &Self {
@@ -319,10 +536,11 @@ pub trait Parser<Input>: ParserMut<Input> {
 impl<P, I> ParserOnce<I> for &mut P
 where
     P: ParserMut<I>,
+    I: Stream,
 {
     type Output = P::Output;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
@@ -330,8 +548,9 @@ where
 impl<P, I> ParserMut<I> for &mut P
 where
     P: ParserMut<I>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         (*self).parse_mut(input)
     }
 }
@@ -339,10 +558,11 @@ where
 impl<P, I> ParserOnce<I> for &P
 where
     P: Parser<I>,
+    I: Stream,
 {
     type Output = P::Output;
 
-    fn parse_once(mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
@@ -350,8 +570,9 @@ where
 impl<P, I> ParserMut<I> for &P
 where
     P: Parser<I>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse(input)
     }
 }
@@ -359,8 +580,9 @@ where
 impl<P, I> Parser<I> for &P
 where
     P: Parser<I>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         (*self).parse(input)
     }
 }
@@ -370,7 +592,11 @@ where
     I: Stream,
     I::Item: PartialEq,
 {
-    satisfy(move |t| t == token)
+    from_fn(move |input: &mut I| {
+        satisfy(|t| t == token)
+            .parse(input)
+            .map_err(|error| error.with_expected(Info::Token(token)))
+    })
 }
 
 pub fn value<I, O>(output: O) -> impl Parser<I, Output = O> + Copy
@@ -378,7 +604,7 @@ where
     I: Stream,
     O: Copy,
 {
-    from_fn(move |_| Some(output))
+    from_fn(move |_| Ok(output))
 }
 
 pub fn any<I>() -> impl Parser<I, Output = I::Item> + Copy
@@ -388,37 +614,102 @@ where
     satisfy_map(Some)
 }
 
+/// Returns the next item without consuming it.
+pub fn peek<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+{
+    lookahead(any())
+}
+
 pub fn eof<I>() -> impl Parser<I, Output = ()> + Copy
 where
     I: Stream,
 {
-    from_fn(|input: &mut I| if input.is_empty() { Some(()) } else { None })
+    from_fn(|input: &mut I| {
+        if input.is_empty() && !input.is_partial() {
+            Ok(())
+        } else {
+            Err(Error::at(input).with_expected(Info::EndOfInput))
+        }
+    })
 }
 
 #[derive(Copy, Clone)]
 struct String<'a>(&'a str);
 
-impl<'a> ParserOnce<&'a str> for String<'a> {
+impl<I> ParserOnce<I> for String<'_>
+where
+    I: Stream<Item = char>,
+{
     type Output = ();
 
-    fn parse_once(mut self, input: &mut &'a str) -> Option<Self::Output> {
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_mut(input)
     }
 }
 
-impl<'a> ParserMut<&'a str> for String<'a> {
-    fn parse_mut(&mut self, input: &mut &'a str) -> Option<Self::Output> {
+impl<I> ParserMut<I> for String<'_>
+where
+    I: Stream<Item = char>,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse(input)
     }
 }
 
-impl<'a> Parser<&'a str> for String<'a> {
-    fn parse(&self, input: &mut &'a str) -> Option<Self::Output> {
+impl<I> Parser<I> for String<'_>
+where
+    I: Stream<Item = char>,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         tokens(self.0.chars()).parse_once(input)
     }
 }
 
-pub fn string(string: &str) -> impl Parser<&str, Output = ()> + Copy {
+#[derive(Copy, Clone)]
+struct ByteString<'a>(&'a [u8]);
+
+impl<I> ParserOnce<I> for ByteString<'_>
+where
+    I: Stream<Item = u8>,
+{
+    type Output = ();
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<I> ParserMut<I> for ByteString<'_>
+where
+    I: Stream<Item = u8>,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<I> Parser<I> for ByteString<'_>
+where
+    I: Stream<Item = u8>,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        tokens(self.0.iter().copied()).parse_once(input)
+    }
+}
+
+pub fn bytes<I>(bytes: &[u8]) -> impl Parser<I, Output = ()> + Copy + '_
+where
+    I: Stream<Item = u8>,
+{
+    ByteString(bytes)
+}
+
+pub fn string<I>(string: &str) -> impl Parser<I, Output = ()> + Copy + '_
+where
+    I: Stream<Item = char>,
+{
     String(string)
 }
 
@@ -429,11 +720,80 @@ mod test {
     #[test]
     fn test_string() {
         let mut input = "abcde";
-        assert_eq!(string("abc").recognize().parse(&mut input), Some("abc"));
+        assert_eq!(string("abc").recognize().parse(&mut input), Ok("abc"));
         assert_eq!(input, "de");
 
         let mut input = "abde";
-        assert_eq!(string("abc").recognize().parse(&mut input), None);
+        let error = string("abc").recognize().parse(&mut input).unwrap_err();
+        assert_eq!(error.unexpected(), Some(&Info::Token('d')));
+        assert_eq!(error.expected(), &[Info::Token('c')]);
         assert_eq!(input, "de");
     }
+
+    #[test]
+    fn test_lookahead() {
+        let keyword =
+            |word| string(word).followed_by(not_followed_by(satisfy(char::is_alphanumeric)));
+        let mut input = "if x";
+        assert!(keyword("if").parse(&mut input).is_ok());
+        assert_eq!(input, " x");
+
+        let mut input = "iffy";
+        let error = keyword("if").parse(&mut input).unwrap_err();
+        assert_eq!(error.unexpected(), Some(&Info::Token('f')));
+        assert_eq!(input, "fy");
+
+        let mut input = "abc";
+        assert_eq!(
+            lookahead(string("ab").recognize()).parse(&mut input),
+            Ok("ab")
+        );
+        assert!(lookahead(string("ac")).parse(&mut input).is_err());
+        assert_eq!(peek().parse(&mut input), Ok('a'));
+        assert_eq!(input, "abc");
+        assert!(peek().parse(&mut "").is_err());
+    }
+
+    #[test]
+    fn test_many_till() {
+        let comment = chain((
+            string("<!--"),
+            any().many_till::<_, std::string::String>(string("-->")),
+        ));
+        let mut input = "<!-- a -- b -->c";
+        assert_eq!(
+            comment.parse(&mut input),
+            Ok(((), (" a -- b ".to_string(), ())))
+        );
+        assert_eq!(input, "c");
+        assert!(comment.parse_to_end("<!-- a -").is_err());
+
+        let digits = digit().many_till::<_, Vec<_>>(token(';'));
+        assert_eq!(digits.parse_to_end("12;"), Ok((vec![1, 2], ';')));
+        let error = digits.parse_to_end("12x").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'x', expected ';' or digit");
+
+        let letter = any().map(|c: char| if c == 'x' { Err(c) } else { Ok(c) });
+        let letters = letter.many_till::<_, Result<Vec<_>, _>>(token(';'));
+        let mut input = "axb;c";
+        assert_eq!(letters.parse(&mut input), Ok((Err('x'), ';')));
+        assert_eq!(input, "c");
+    }
+
+    #[test]
+    fn test_take_until() {
+        let comment = chain((string("/*"), take_until(string("*/")), string("*/")));
+        let mut input = "/* a * b */c";
+        assert_eq!(comment.parse(&mut input), Ok(((), " a * b ", ())));
+        assert_eq!(input, "c");
+
+        let mut input = "/* a *";
+        let error = comment.parse(&mut input).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected end of input, expected '*'");
+
+        let error = take_until(string("*/").cut())
+            .parse_to_end("a */")
+            .unwrap_err();
+        assert!(error.is_fatal());
+    }
 }
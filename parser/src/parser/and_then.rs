@@ -10,11 +10,14 @@ impl<P, F, I, O> ParserOnce<I> for AndThen<P, F>
 where
     P: ParserOnce<I>,
     F: FnOnce(P::Output) -> Option<O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        self.parser.parse_once(input).and_then(self.f)
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let output = self.parser.parse_once(input)?;
+        (self.f)(output).ok_or_else(|| Error::new(position))
     }
 }
 
@@ -22,9 +25,12 @@ impl<P, F, I, O> ParserMut<I> for AndThen<P, F>
 where
     P: ParserMut<I>,
     F: FnMut(P::Output) -> Option<O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.parser.parse_mut(input).and_then(&mut self.f)
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let output = self.parser.parse_mut(input)?;
+        (self.f)(output).ok_or_else(|| Error::new(position))
     }
 }
 
@@ -32,9 +38,12 @@ impl<P, F, I, O> Parser<I> for AndThen<P, F>
 where
     P: Parser<I>,
     F: Fn(P::Output) -> Option<O>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        self.parser.parse(input).and_then(&self.f)
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let position = input.position();
+        let output = self.parser.parse(input)?;
+        (self.f)(output).ok_or_else(|| Error::new(position))
     }
 }
 
@@ -10,12 +10,14 @@ where
 {
     type Output = P::Output;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         let copy = *input;
-        self.0.parse_once(input).or_else(|| {
+        copy.retain();
+        let result = self.0.parse_once(input).inspect_err(|_| {
             *input = copy;
-            None
-        })
+        });
+        copy.release();
+        result
     }
 }
 
@@ -24,12 +26,14 @@ where
     P: ParserMut<I>,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         let copy = *input;
-        self.0.parse_mut(input).or_else(|| {
+        copy.retain();
+        let result = self.0.parse_mut(input).inspect_err(|_| {
             *input = copy;
-            None
-        })
+        });
+        copy.release();
+        result
     }
 }
 
@@ -38,12 +42,14 @@ where
     P: Parser<I>,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         let copy = *input;
-        self.0.parse(input).or_else(|| {
+        copy.retain();
+        let result = self.0.parse(input).inspect_err(|_| {
             *input = copy;
-            None
-        })
+        });
+        copy.release();
+        result
     }
 }
 
@@ -12,10 +12,11 @@ where
     P: ParserOnce<I>,
     L: ParserOnce<I>,
     R: ParserOnce<I>,
+    I: Stream,
 {
     type Output = P::Output;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         chain((self.left, self.parser, self.right))
             .map_once(|(_, output, _)| output)
             .parse_once(input)
@@ -27,8 +28,9 @@ where
     P: ParserMut<I>,
     L: ParserMut<I>,
     R: ParserMut<I>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         chain((&mut self.left, &mut self.parser, &mut self.right))
             .map_mut(|(_, output, _)| output)
             .parse_mut(input)
@@ -40,8 +42,9 @@ where
     P: Parser<I>,
     L: Parser<I>,
     R: Parser<I>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         chain((&self.left, &self.parser, &self.right))
             .map(|(_, output, _)| output)
             .parse(input)
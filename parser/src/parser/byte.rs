@@ -0,0 +1,67 @@
+use super::*;
+
+pub fn digit<I>() -> impl Parser<I, Output = u32> + Copy
+where
+    I: Stream<Item = u8>,
+{
+    satisfy_map(|b: u8| char::from(b).to_digit(10)).label("digit")
+}
+
+macro_rules! integer {
+    ($x:ident) => {
+        pub fn $x<I>() -> impl Parser<I, Output = $x> + Copy
+        where
+            I: Stream<Item = u8>,
+        {
+            super::num::integer(10, None, "digit", false)
+        }
+    };
+}
+
+integer!(u8);
+integer!(u16);
+integer!(u32);
+integer!(u64);
+integer!(u128);
+integer!(usize);
+integer!(i8);
+integer!(i16);
+integer!(i32);
+integer!(i64);
+integer!(i128);
+integer!(isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_number() {
+        let mut input = Bytes::new(b"123abc");
+        assert_eq!(super::u32().parse(&mut input), Ok(123));
+        assert_eq!(input, Bytes::new(b"abc"));
+
+        let mut input = Bytes::new(b"-129,");
+        assert!(super::i8().parse(&mut input).is_err());
+        assert_eq!(super::i16().parse_partial(Bytes::new(b"-129,")), Ok(-129));
+        assert_eq!(super::i8().parse_partial(Bytes::new(b"+12")), Ok(12));
+
+        let error = super::u8().parse_to_end(Bytes::new(b"256")).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected integer overflow");
+        let error = super::u8().parse_to_end(Bytes::new(b"x")).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 120, expected digit");
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut input = Bytes::new(b"GET /");
+        assert_eq!(
+            bytes(b"GET")
+                .followed_by(token(b' '))
+                .recognize()
+                .parse(&mut input),
+            Ok(&b"GET "[..])
+        );
+        assert_eq!(input, Bytes::new(b"/"));
+    }
+}
@@ -0,0 +1,186 @@
+use super::*;
+
+/// Stream items that can be interpreted as characters. Bytes are only treated
+/// as characters if they are ASCII.
+pub trait AsChar: Copy {
+    fn as_char(self) -> Option<char>;
+
+    /// Whether this item occurs in `set`. Bytes are compared against the
+    /// UTF-8 encoding of `set`, so that non-ASCII bytes can be matched too.
+    fn is_in(self, set: &str) -> bool;
+}
+
+impl AsChar for char {
+    fn as_char(self) -> Option<char> {
+        Some(self)
+    }
+
+    fn is_in(self, set: &str) -> bool {
+        set.contains(self)
+    }
+}
+
+impl AsChar for u8 {
+    fn as_char(self) -> Option<char> {
+        if self.is_ascii() {
+            Some(char::from(self))
+        } else {
+            None
+        }
+    }
+
+    fn is_in(self, set: &str) -> bool {
+        set.as_bytes().contains(&self)
+    }
+}
+
+fn class<I>(
+    f: impl Fn(char) -> bool + Copy,
+    label: &'static str,
+) -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    satisfy(move |item: I::Item| item.as_char().is_some_and(f)).label(label)
+}
+
+/// Parses any of the characters in `set`.
+pub fn one_of<I>(set: &str) -> impl Parser<I, Output = I::Item> + Copy + '_
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    from_fn(move |input: &mut I| {
+        satisfy(|item: I::Item| item.is_in(set))
+            .parse(input)
+            .map_err(|error| error.with_label(format!("one of {:?}", set)))
+    })
+}
+
+/// Parses any character that isn't in `set`.
+pub fn none_of<I>(set: &str) -> impl Parser<I, Output = I::Item> + Copy + '_
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    from_fn(move |input: &mut I| {
+        satisfy(|item: I::Item| !item.is_in(set))
+            .parse(input)
+            .map_err(|error| error.with_label(format!("none of {:?}", set)))
+    })
+}
+
+pub fn alpha<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    class(char::is_alphabetic, "letter")
+}
+
+pub fn alphanumeric<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    class(char::is_alphanumeric, "letter or digit")
+}
+
+pub fn hex_digit<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    class(|c| c.is_ascii_hexdigit(), "hexadecimal digit")
+}
+
+pub fn oct_digit<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    class(|c| c.is_digit(8), "octal digit")
+}
+
+pub fn whitespace<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    class(char::is_whitespace, "whitespace")
+}
+
+pub fn newline<I>() -> impl Parser<I, Output = I::Item> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    class(|c| c == '\n', "newline")
+}
+
+/// Parses `\n` or `\r\n`.
+pub fn line_ending<I>() -> impl Parser<I, Output = I::Range> + Copy
+where
+    I: Stream,
+    I::Item: AsChar,
+{
+    let carriage_return = class(|c| c == '\r', "carriage return");
+    choice((
+        newline(),
+        carriage_return.followed_by(newline()).map(|(c, _)| c),
+    ))
+    .recognize()
+    .label("line ending")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_of() {
+        let mut input = "+-x";
+        let op = one_of("+-*/");
+        assert_eq!(op.parse(&mut input), Ok('+'));
+        assert_eq!(op.parse(&mut input), Ok('-'));
+        let error = op.parse(&mut input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected 'x', expected one of \"+-*/\""
+        );
+
+        let mut input = Bytes::new(b"ab\"");
+        let unquoted = none_of("\"\\").skip_many().recognize();
+        assert_eq!(unquoted.parse(&mut input), Ok(&b"ab"[..]));
+        assert_eq!(input, Bytes::new(b"\""));
+        assert!(none_of("\"").parse(&mut input).is_err());
+
+        let mut input = Bytes::new("é\"".as_bytes());
+        assert_eq!(unquoted.parse(&mut input), Ok("é".as_bytes()));
+        assert_eq!(input, Bytes::new(b"\""));
+        assert_eq!(one_of("é").parse_partial(Bytes::new(&[0xA9])), Ok(0xA9));
+    }
+
+    #[test]
+    fn test_classes() {
+        let identifier = chain((alpha(), alphanumeric().skip_many())).recognize();
+        assert_eq!(identifier.parse_partial("é1 x"), Ok("é1"));
+        let error = identifier.parse_to_end("1").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected '1', expected letter");
+
+        let hex = hex_digit().skip_many1().recognize();
+        assert_eq!(hex.parse_partial(Bytes::new(b"fF09g")), Ok(&b"fF09"[..]));
+        assert!(alpha().parse_partial(Bytes::new("é".as_bytes())).is_err());
+        assert_eq!(oct_digit().parse_partial("7"), Ok('7'));
+        assert!(oct_digit().parse_partial("8").is_err());
+        assert_eq!(whitespace().parse_partial(Bytes::new(b"\t")), Ok(b'\t'));
+
+        let mut input = "\r\n\nx";
+        assert_eq!(line_ending().parse(&mut input), Ok("\r\n"));
+        assert_eq!(line_ending().parse(&mut input), Ok("\n"));
+        let error = line_ending().parse(&mut input).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'x', expected line ending");
+        assert_eq!(newline().parse_partial(Bytes::new(b"\n")), Ok(b'\n'));
+    }
+}
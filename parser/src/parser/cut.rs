@@ -0,0 +1,40 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct Cut<P>(P);
+
+impl<P, I> ParserOnce<I> for Cut<P>
+where
+    P: ParserOnce<I>,
+    I: Stream,
+{
+    type Output = P::Output;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.0.parse_once(input).map_err(Error::into_fatal)
+    }
+}
+
+impl<P, I> ParserMut<I> for Cut<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.0.parse_mut(input).map_err(Error::into_fatal)
+    }
+}
+
+impl<P, I> Parser<I> for Cut<P>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.0.parse(input).map_err(Error::into_fatal)
+    }
+}
+
+pub fn cut<P>(parser: P) -> Cut<P> {
+    Cut(parser)
+}
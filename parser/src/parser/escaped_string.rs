@@ -0,0 +1,189 @@
+use super::*;
+use std::borrow::Cow;
+
+/// A quoted string in which the escape character introduces either one of the
+/// escapes from the table, or a unicode escape.
+pub struct EscapedString<'t, I> {
+    quote: char,
+    escape: char,
+    table: &'t [(char, char)],
+    unicode: Option<char>,
+    _marker: PhantomData<I>,
+}
+
+impl<I> Copy for EscapedString<'_, I> {}
+
+impl<I> Clone for EscapedString<'_, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, I> EscapedString<'t, I> {
+    /// Sets the escapes, each of which maps the character following the escape
+    /// character to the character it stands for.
+    pub fn escapes<'u>(self, table: &'u [(char, char)]) -> EscapedString<'u, I> {
+        EscapedString {
+            quote: self.quote,
+            escape: self.escape,
+            table,
+            unicode: self.unicode,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Enables unicode escapes introduced by `c`, which are either of the form
+    /// `\u{1F600}` or `\uXXXX`, where a high surrogate has to be followed by an
+    /// escaped low surrogate.
+    pub fn unicode(self, c: char) -> Self {
+        EscapedString {
+            unicode: Some(c),
+            ..self
+        }
+    }
+
+    fn unescape<'a>(&self, input: &mut I) -> Result<char, Error<I>>
+    where
+        I: Stream<Item = char, Range = &'a str>,
+    {
+        let checkpoint = *input;
+        let c = any().parse(input)?;
+        if let Some(&(_, output)) = self.table.iter().find(|&&(escape, _)| escape == c) {
+            return Ok(output);
+        }
+        if self.unicode != Some(c) {
+            return Err(Error::at(&checkpoint).with_label("escape sequence"));
+        }
+
+        let position = input.position();
+        let hex =
+            |m, n| take_while_m_n(m, n, |c: char| c.is_ascii_hexdigit()).label("hexadecimal digit");
+        let code = if token('{').optional().parse(input)?.is_some() {
+            let digits = hex(1, 6).parse(input)?;
+            token('}').parse(input)?;
+            u32::from_str_radix(digits, 16).unwrap()
+        } else {
+            let high = u32::from_str_radix(hex(4, 4).parse(input)?, 16).unwrap();
+            if (0xD800..0xDC00).contains(&high) {
+                let low = chain((token(self.escape), token(c), hex(4, 4)))
+                    .map(|(_, _, digits)| u32::from_str_radix(digits, 16).unwrap())
+                    .parse(input)?;
+                if !(0xDC00..0xE000).contains(&low) {
+                    return Err(invalid_unicode(position));
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            } else {
+                high
+            }
+        };
+        char::from_u32(code).ok_or_else(|| invalid_unicode(position))
+    }
+}
+
+fn invalid_unicode<I>(position: I::Position) -> Error<I>
+where
+    I: Stream,
+{
+    Error::new(position).with_unexpected(Info::Label("invalid unicode escape".into()))
+}
+
+impl<'a, I> ParserOnce<I> for EscapedString<'_, I>
+where
+    I: Stream<Item = char, Range = &'a str>,
+{
+    type Output = Cow<'a, str>;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<'a, I> ParserMut<I> for EscapedString<'_, I>
+where
+    I: Stream<Item = char, Range = &'a str>,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<'a, I> Parser<I> for EscapedString<'_, I>
+where
+    I: Stream<Item = char, Range = &'a str>,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        token(self.quote).parse(input)?;
+        let start = *input;
+        // only allocated once the first escape is found
+        let mut owned: Option<std::string::String> = None;
+        loop {
+            let end = *input;
+            let c = any()
+                .parse(input)
+                .map_err(|error| error.with_expected(Info::Token(self.quote)))?;
+            if c == self.quote {
+                return Ok(match owned {
+                    Some(owned) => Cow::Owned(owned),
+                    None => Cow::Borrowed(end.slice_since(&start)),
+                });
+            }
+            if c == self.escape {
+                let owned = owned.get_or_insert_with(|| end.slice_since(&start).to_owned());
+                owned.push(self.unescape(input)?);
+            } else if let Some(owned) = &mut owned {
+                owned.push(c);
+            }
+        }
+    }
+}
+
+/// Starts building a parser for strings delimited by `quote`, which can only be
+/// escaped with `escape` once escapes are added with `escapes` or `unicode`.
+pub fn escaped_string<I>(quote: char, escape: char) -> EscapedString<'static, I> {
+    EscapedString {
+        quote,
+        escape,
+        table: &[],
+        unicode: None,
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_string<'a>() -> impl Parser<&'a str, Output = Cow<'a, str>> {
+        escaped_string('"', '\\')
+            .escapes(&[('n', '\n'), ('t', '\t'), ('"', '"'), ('\\', '\\')])
+            .unicode('u')
+    }
+
+    #[test]
+    fn test_escaped_string() {
+        let mut input = r#""plain" rest"#;
+        let output = json_string().parse(&mut input).unwrap();
+        assert!(matches!(output, Cow::Borrowed("plain")));
+        assert_eq!(input, " rest");
+
+        let output = json_string().parse_to_end(r#""a\"b\\c\td\n""#).unwrap();
+        assert!(matches!(output, Cow::Owned(_)));
+        assert_eq!(output, "a\"b\\c\td\n");
+
+        let parse = |input| json_string().parse_to_end(input).map(Cow::into_owned);
+        assert_eq!(parse(r#""\u{1F600}é""#), Ok("\u{1F600}é".to_string()));
+        assert_eq!(parse(r#""\ud83d\ude00!""#), Ok("\u{1F600}!".to_string()));
+
+        let error = parse(r#""\x""#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected 'x', expected escape sequence"
+        );
+        let error = parse(r#""\ud83d""#).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected '\"', expected '\\\\'");
+        let error = parse(r#""\udc00""#).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected invalid unicode escape");
+        let error = parse(r#""abc"#).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected end of input, expected '\"'");
+    }
+}
@@ -11,14 +11,13 @@ where
     P: ParserOnce<I>,
     Q: ParserOnce<I>,
     F: FnOnce(P::Output) -> Q,
+    I: Stream,
 {
     type Output = Q::Output;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        let f = self.f;
-        self.parser
-            .parse_once(input)
-            .and_then(|o| f(o).parse_once(input))
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let output = self.parser.parse_once(input)?;
+        (self.f)(output).parse_once(input)
     }
 }
 
@@ -27,11 +26,11 @@ where
     P: ParserMut<I>,
     Q: ParserOnce<I>,
     F: FnMut(P::Output) -> Q,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.parser
-            .parse_mut(input)
-            .and_then(|o| (self.f)(o).parse_once(input))
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let output = self.parser.parse_mut(input)?;
+        (self.f)(output).parse_once(input)
     }
 }
 
@@ -40,11 +39,11 @@ where
     P: Parser<I>,
     Q: ParserOnce<I>,
     F: Fn(P::Output) -> Q,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        self.parser
-            .parse(input)
-            .and_then(|o| (self.f)(o).parse_once(input))
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let output = self.parser.parse(input)?;
+        (self.f)(output).parse_once(input)
     }
 }
 
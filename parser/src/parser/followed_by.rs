@@ -7,14 +7,14 @@ impl<P, Q, I> ParserOnce<I> for FollowedBy<P, Q>
 where
     P: ParserOnce<I>,
     Q: ParserOnce<I>,
+    I: Stream,
 {
     type Output = (P::Output, Q::Output);
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        let q = self.1;
-        self.0
-            .parse_once(input)
-            .and_then(|output1| q.parse_once(input).map(move |output2| (output1, output2)))
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let output1 = self.0.parse_once(input)?;
+        let output2 = self.1.parse_once(input)?;
+        Ok((output1, output2))
     }
 }
 
@@ -22,13 +22,12 @@ impl<P, Q, I> ParserMut<I> for FollowedBy<P, Q>
 where
     P: ParserMut<I>,
     Q: ParserMut<I>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.0.parse_mut(input).and_then(|output1| {
-            self.1
-                .parse_mut(input)
-                .map(move |output2| (output1, output2))
-        })
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let output1 = self.0.parse_mut(input)?;
+        let output2 = self.1.parse_mut(input)?;
+        Ok((output1, output2))
     }
 }
 
@@ -36,11 +35,12 @@ impl<P, Q, I> Parser<I> for FollowedBy<P, Q>
 where
     P: Parser<I>,
     Q: Parser<I>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .parse(input)
-            .and_then(|output1| self.1.parse(input).map(move |output2| (output1, output2)))
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let output1 = self.0.parse(input)?;
+        let output2 = self.1.parse(input)?;
+        Ok((output1, output2))
     }
 }
 
@@ -17,29 +17,32 @@ where
 
 impl<F, I, O> ParserOnce<I> for FromFn<F>
 where
-    F: FnOnce(&mut I) -> Option<O>,
+    F: FnOnce(&mut I) -> Result<O, Error<I>>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         (self.f)(input)
     }
 }
 
 impl<F, I, O> ParserMut<I> for FromFn<F>
 where
-    F: FnMut(&mut I) -> Option<O>,
+    F: FnMut(&mut I) -> Result<O, Error<I>>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         (self.f)(input)
     }
 }
 
 impl<F, I, O> Parser<I> for FromFn<F>
 where
-    F: Fn(&mut I) -> Option<O>,
+    F: Fn(&mut I) -> Result<O, Error<I>>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         (self.f)(input)
     }
 }
@@ -47,21 +50,24 @@ where
 // TODO: remove this and `from_fn_mut` in favor of `from_fn`
 pub fn from_fn_once<F, I, O>(f: F) -> FromFn<F>
 where
-    F: FnOnce(&mut I) -> Option<O>,
+    I: Stream,
+    F: FnOnce(&mut I) -> Result<O, Error<I>>,
 {
     FromFn { f }
 }
 
 pub fn from_fn_mut<F, I, O>(f: F) -> FromFn<F>
 where
-    F: FnMut(&mut I) -> Option<O>,
+    I: Stream,
+    F: FnMut(&mut I) -> Result<O, Error<I>>,
 {
     FromFn { f }
 }
 
 pub fn from_fn<F, I, O>(f: F) -> FromFn<F>
 where
-    F: Fn(&mut I) -> Option<O>,
+    I: Stream,
+    F: Fn(&mut I) -> Result<O, Error<I>>,
 {
     FromFn { f }
 }
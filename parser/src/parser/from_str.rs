@@ -10,10 +10,11 @@ impl<'a, P, I, O> ParserOnce<I> for FromStr<P, O>
 where
     P: ParserOnce<I, Output = &'a str>,
     O: std::str::FromStr,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser
             .and_then_once(|s: &str| s.parse().ok())
             .parse_once(input)
@@ -24,8 +25,9 @@ impl<'a, P, I, O> ParserMut<I> for FromStr<P, O>
 where
     P: ParserMut<I, Output = &'a str>,
     O: std::str::FromStr,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser
             .by_mut_ref()
             .and_then_mut(|s: &str| s.parse().ok())
@@ -37,8 +39,9 @@ impl<'a, P, I, O> Parser<I> for FromStr<P, O>
 where
     P: Parser<I, Output = &'a str>,
     O: std::str::FromStr,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser
             .by_ref()
             .and_then(|s: &str| s.parse().ok())
@@ -0,0 +1,52 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct Label<P> {
+    parser: P,
+    label: &'static str,
+}
+
+impl<P, I> ParserOnce<I> for Label<P>
+where
+    P: ParserOnce<I>,
+    I: Stream,
+{
+    type Output = P::Output;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        match self.parser.parse_once_and_check_consumed(input) {
+            (Err(error), false) => Err(error.with_label(self.label)),
+            (result, _) => result,
+        }
+    }
+}
+
+impl<P, I> ParserMut<I> for Label<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        match self.parser.parse_mut_and_check_consumed(input) {
+            (Err(error), false) => Err(error.with_label(self.label)),
+            (result, _) => result,
+        }
+    }
+}
+
+impl<P, I> Parser<I> for Label<P>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        match self.parser.parse_and_check_consumed(input) {
+            (Err(error), false) => Err(error.with_label(self.label)),
+            (result, _) => result,
+        }
+    }
+}
+
+pub fn label<P>(parser: P, label: &'static str) -> Label<P> {
+    Label { parser, label }
+}
@@ -0,0 +1,210 @@
+use super::*;
+
+struct Node<T> {
+    /// Sorted by character, pointing to the index of the next node.
+    edges: Vec<(char, usize)>,
+    value: Option<T>,
+}
+
+impl<T> Clone for Node<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Node {
+            edges: self.edges.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+/// A set of literals compiled into a trie, which is matched against the input
+/// in a single pass.
+pub struct LiteralMap<T, I> {
+    nodes: Vec<Node<T>>,
+    _marker: PhantomData<I>,
+}
+
+impl<T, I> Clone for LiteralMap<T, I>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        LiteralMap {
+            nodes: self.nodes.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, I> LiteralMap<T, I> {
+    fn insert(&mut self, literal: &str, value: T) {
+        let mut node = 0;
+        for c in literal.chars() {
+            node = match self.nodes[node].edges.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(i) => self.nodes[node].edges[i].1,
+                Err(i) => {
+                    let next = self.nodes.len();
+                    self.nodes[node].edges.insert(i, (c, next));
+                    self.nodes.push(Node {
+                        edges: Vec::new(),
+                        value: None,
+                    });
+                    next
+                }
+            };
+        }
+        self.nodes[node].value = Some(value);
+    }
+
+    fn next(&self, node: usize, c: char) -> Option<usize> {
+        let edges = &self.nodes[node].edges;
+        let i = edges.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+        Some(edges[i].1)
+    }
+}
+
+impl<T, I> ParserOnce<I> for LiteralMap<T, I>
+where
+    T: Clone,
+    I: Stream<Item = char>,
+{
+    type Output = T;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<T, I> ParserMut<I> for LiteralMap<T, I>
+where
+    T: Clone,
+    I: Stream<Item = char>,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<T, I> Parser<I> for LiteralMap<T, I>
+where
+    T: Clone,
+    I: Stream<Item = char>,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let mut copy = *input;
+        let mut node = 0;
+        let mut longest = self.nodes[0].value.as_ref().map(|value| (value, copy));
+        while let Some(next) = copy.uncons_map(|c| self.next(node, c)) {
+            node = next;
+            if let Some(value) = &self.nodes[node].value {
+                longest = Some((value, copy));
+            }
+        }
+
+        // a longer literal might still match once more input is available
+        if copy.is_empty() && copy.is_partial() && !self.nodes[node].edges.is_empty() {
+            return Err(Error::incomplete(copy.position(), 1));
+        }
+        match longest {
+            Some((value, end)) => {
+                *input = end;
+                Ok(value.clone())
+            }
+            None => {
+                let error = Error::at(&copy);
+                Err(self.nodes[node]
+                    .edges
+                    .iter()
+                    .fold(error, |error, &(c, _)| error.with_expected(Info::Token(c))))
+            }
+        }
+    }
+}
+
+/// Parses the longest of the given literals that matches the input, and
+/// returns the value associated with it. Later entries replace earlier entries
+/// for the same literal.
+pub fn literal_map<T, I>(literals: &[(&str, T)]) -> LiteralMap<T, I>
+where
+    T: Clone,
+{
+    let mut map = LiteralMap {
+        nodes: vec![Node {
+            edges: Vec::new(),
+            value: None,
+        }],
+        _marker: PhantomData,
+    };
+    for (literal, value) in literals {
+        map.insert(literal, value.clone());
+    }
+    map
+}
+
+/// Parses the longest of the given literals that matches the input.
+pub fn literals<I>(literals: &[&str]) -> impl Parser<I, Output = I::Range> + Clone
+where
+    I: Stream<Item = char>,
+{
+    let pairs: Vec<_> = literals.iter().map(|&literal| (literal, ())).collect();
+    literal_map(&pairs).recognize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum Token {
+        True,
+        False,
+        Less,
+        LessEqual,
+        Shift,
+    }
+
+    #[test]
+    fn test_literals() {
+        let mut input = "<=<<=";
+        let operator = literals(&["<=", "<", "==", "="]);
+        assert_eq!(operator.parse(&mut input), Ok("<="));
+        assert_eq!(operator.parse(&mut input), Ok("<"));
+        assert_eq!(operator.parse(&mut input), Ok("<="));
+        assert!(operator.parse(&mut input).is_err());
+
+        let error = operator.parse_to_end("!=").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected '!', expected '<' or '='");
+    }
+
+    #[test]
+    fn test_literal_map() {
+        let token = literal_map(&[
+            ("true", Token::True),
+            ("false", Token::False),
+            ("<", Token::Less),
+            ("<=", Token::LessEqual),
+            ("<<", Token::Shift),
+        ]);
+        let mut input = "tru<=<<x";
+        let error = token.parse(&mut input).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected '<', expected 'e'");
+        assert_eq!(*error.position(), input[3..].as_ptr());
+        assert_eq!(input, "tru<=<<x");
+
+        let mut input = "true<=<<x";
+        assert_eq!(token.parse(&mut input), Ok(Token::True));
+        assert_eq!(token.parse(&mut input), Ok(Token::LessEqual));
+        assert_eq!(token.parse(&mut input), Ok(Token::Shift));
+        assert_eq!(input, "x");
+
+        let mut input = Partial::new("<");
+        let operator = literal_map(&[("<", Token::Less), ("<=", Token::LessEqual)]);
+        let error = operator.parse(&mut input).unwrap_err();
+        assert_eq!(error.needed(), Some(1));
+        assert_eq!(
+            literal_map(&[("<", Token::Less)]).parse(&mut input),
+            Ok(Token::Less)
+        );
+    }
+}
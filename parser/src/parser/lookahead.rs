@@ -0,0 +1,51 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct Lookahead<P>(P);
+
+impl<P, I> ParserOnce<I> for Lookahead<P>
+where
+    P: ParserOnce<I>,
+    I: Stream,
+{
+    type Output = P::Output;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        input.retain();
+        let result = self.0.parse_once(&mut { *input });
+        input.release();
+        result
+    }
+}
+
+impl<P, I> ParserMut<I> for Lookahead<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        input.retain();
+        let result = self.0.parse_mut(&mut { *input });
+        input.release();
+        result
+    }
+}
+
+impl<P, I> Parser<I> for Lookahead<P>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        input.retain();
+        let result = self.0.parse(&mut { *input });
+        input.release();
+        result
+    }
+}
+
+/// Runs `parser` without consuming any input, regardless of whether it
+/// succeeds.
+pub fn lookahead<P>(parser: P) -> Lookahead<P> {
+    Lookahead(parser)
+}
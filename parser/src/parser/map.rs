@@ -10,10 +10,11 @@ impl<P, F, I, O> ParserOnce<I> for Map<P, F>
 where
     P: ParserOnce<I>,
     F: FnOnce(P::Output) -> O,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser.parse_once(input).map(self.f)
     }
 }
@@ -22,8 +23,9 @@ impl<P, F, I, O> ParserMut<I> for Map<P, F>
 where
     P: ParserMut<I>,
     F: FnMut(P::Output) -> O,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser.parse_mut(input).map(&mut self.f)
     }
 }
@@ -32,8 +34,9 @@ impl<P, F, I, O> Parser<I> for Map<P, F>
 where
     P: Parser<I>,
     F: Fn(P::Output) -> O,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser.parse(input).map(&self.f)
     }
 }
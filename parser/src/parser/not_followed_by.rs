@@ -0,0 +1,52 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct NotFollowedBy<P>(P);
+
+fn check<O, I>(result: Result<O, Error<I>>, input: &I) -> Result<(), Error<I>>
+where
+    I: Stream,
+{
+    match result {
+        Ok(_) => Err(Error::at(input)),
+        Err(error) if error.is_incomplete() => Err(error),
+        Err(_) => Ok(()),
+    }
+}
+
+impl<P, I> ParserOnce<I> for NotFollowedBy<P>
+where
+    P: ParserOnce<I>,
+    I: Stream,
+{
+    type Output = ();
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        check(self.0.parse_once(&mut { *input }), input)
+    }
+}
+
+impl<P, I> ParserMut<I> for NotFollowedBy<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        check(self.0.parse_mut(&mut { *input }), input)
+    }
+}
+
+impl<P, I> Parser<I> for NotFollowedBy<P>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        check(self.0.parse(&mut { *input }), input)
+    }
+}
+
+/// Succeeds without consuming any input if `parser` fails at this position.
+pub fn not_followed_by<P>(parser: P) -> NotFollowedBy<P> {
+    NotFollowedBy(parser)
+}
@@ -4,37 +4,217 @@ pub fn digit<I>() -> impl Parser<I, Output = u32> + Copy
 where
     I: Stream<Item = char>,
 {
-    satisfy_map(|c: char| c.to_digit(10))
+    satisfy_map(|c: char| c.to_digit(10)).label("digit")
 }
 
-macro_rules! unsigned {
-    ($x:ident) => {
-        pub fn $x<'a>() -> impl Parser<&'a str, Output = $x> + Copy {
-            digit().skip_many1().recognize().from_str()
+/// Integer types that can be parsed from digits in any radix.
+pub trait Integer: Copy {
+    const SIGNED: bool;
+    const ZERO: Self;
+
+    /// Appends a digit, subtracting it instead if the number is negative, or
+    /// returns `None` on overflow.
+    fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($x:ident, $signed:literal) => {
+        impl Integer for $x {
+            const SIGNED: bool = $signed;
+            const ZERO: Self = 0;
+
+            fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self> {
+                let shifted = self.checked_mul(radix as $x)?;
+                if negative {
+                    shifted.checked_sub(digit as $x)
+                } else {
+                    shifted.checked_add(digit as $x)
+                }
+            }
+        }
+
+        pub fn $x<I>() -> impl Parser<I, Output = $x> + Copy
+        where
+            I: Stream<Item = char>,
+        {
+            integer(10, None, "digit", false)
         }
     };
 }
 
-unsigned!(u8);
-unsigned!(u16);
-unsigned!(u32);
-unsigned!(u64);
-unsigned!(u128);
-unsigned!(usize);
+integer!(u8, false);
+integer!(u16, false);
+integer!(u32, false);
+integer!(u64, false);
+integer!(u128, false);
+integer!(usize, false);
+integer!(i8, true);
+integer!(i16, true);
+integer!(i32, true);
+integer!(i64, true);
+integer!(i128, true);
+integer!(isize, true);
+
+/// Parses an optionally signed integer, with an optional `0` + `prefix` in
+/// front of its digits and, if `separators` is set, `_` separators between
+/// them. Numbers that don't fit in `O` are reported as an integer overflow.
+pub(super) fn integer<O, I>(
+    radix: u32,
+    prefix: Option<char>,
+    label: &'static str,
+    separators: bool,
+) -> impl Parser<I, Output = O> + Copy
+where
+    O: Integer,
+    I: Stream,
+    I::Item: AsChar,
+{
+    assert!(
+        (2..=36).contains(&radix),
+        "radix {} is not in 2..=36",
+        radix
+    );
+    let char = |c: char| satisfy(move |item: I::Item| item.as_char() == Some(c));
+    let digit = satisfy_map(move |item: I::Item| item.as_char()?.to_digit(radix)).label(label);
+    // separators are only consumed if a digit follows them
+    let separator = satisfy(move |item: I::Item| separators && item.as_char() == Some('_'));
+    let next_digit = chain((separator.skip_many(), digit))
+        .map(|(_, digit)| digit)
+        .attempt();
+
+    from_fn(move |input: &mut I| {
+        let position = input.position();
+        let negative = O::SIGNED
+            && choice((char('+'), char('-')))
+                .optional()
+                .parse(input)?
+                .and_then(AsChar::as_char)
+                == Some('-');
+
+        if let Some(prefix) = prefix {
+            // the prefix is only skipped if a digit follows, so that `0` on its
+            // own is still a number
+            let mut copy = *input;
+            let prefix = chain((char('0'), char(prefix), lookahead(digit)));
+            if prefix.parse(&mut copy).is_ok() {
+                *input = copy;
+            }
+        }
+
+        let mut value = O::ZERO.push_digit(radix, digit.parse(input)?, negative);
+        while let Some(next) = next_digit.optional().parse(input)? {
+            value = value.and_then(|value| value.push_digit(radix, next, negative));
+        }
+        value.ok_or_else(|| {
+            Error::new(position).with_unexpected(Info::Label("integer overflow".into()))
+        })
+    })
+}
+
+/// Like the base-10 parsers such as `u32`, but also accepts `_` separators
+/// between digits.
+pub fn decimal<O, I>() -> impl Parser<I, Output = O> + Copy
+where
+    O: Integer,
+    I: Stream<Item = char>,
+{
+    integer(10, None, "digit", true)
+}
+
+/// Parses an integer in the given radix, which must lie within `2..=36`.
+pub fn radix<O, I>(radix: u32) -> impl Parser<I, Output = O> + Copy
+where
+    O: Integer,
+    I: Stream<Item = char>,
+{
+    integer(radix, None, "digit", true)
+}
+
+/// Parses a hexadecimal integer, optionally prefixed by `0x`.
+pub fn hex<O, I>() -> impl Parser<I, Output = O> + Copy
+where
+    O: Integer,
+    I: Stream<Item = char>,
+{
+    integer(16, Some('x'), "hexadecimal digit", true)
+}
+
+/// Parses an octal integer, optionally prefixed by `0o`.
+pub fn octal<O, I>() -> impl Parser<I, Output = O> + Copy
+where
+    O: Integer,
+    I: Stream<Item = char>,
+{
+    integer(8, Some('o'), "octal digit", true)
+}
+
+/// Parses a binary integer, optionally prefixed by `0b`.
+pub fn binary<O, I>() -> impl Parser<I, Output = O> + Copy
+where
+    O: Integer,
+    I: Stream<Item = char>,
+{
+    integer(2, Some('b'), "binary digit", true)
+}
+
+fn digits<I>() -> impl Parser<I, Output = ()> + Copy
+where
+    I: Stream<Item = char>,
+{
+    digit().skip_many1()
+}
+
+/// A fraction and exponent, each of which is only consumed if it is complete,
+/// so that `1.` or `1e` leaves the `.` or `e` in the input.
+fn fraction_exponent<I>() -> impl Parser<I, Output = ()> + Copy
+where
+    I: Stream<Item = char>,
+{
+    let fraction = chain((token('.'), digits())).attempt().optional();
+    let exponent = chain((
+        satisfy(|c| c == 'e' || c == 'E'),
+        satisfy(|c| c == '+' || c == '-').optional(),
+        digits(),
+    ))
+    .attempt()
+    .optional();
+    chain((fraction, exponent)).map(|_| ())
+}
+
+macro_rules! float {
+    ($x:ident, $json:ident) => {
+        /// Parses a number with an optional sign, fraction and exponent, or
+        /// one of `inf`, `infinity` and `NaN`.
+        pub fn $x<'a, I>() -> impl Parser<I, Output = $x> + Copy
+        where
+            I: Stream<Item = char, Range = &'a str>,
+        {
+            let special = choice((string("infinity").attempt(), string("inf"), string("NaN")));
+            chain((
+                satisfy(|c| c == '+' || c == '-').optional(),
+                choice((chain((digits(), fraction_exponent())).map(|_| ()), special)),
+            ))
+            .recognize()
+            .from_str()
+        }
 
-macro_rules! signed {
-    ($x:ident) => {
-        pub fn $x<'a>() -> impl Parser<&'a str, Output = $x> + Copy {
-            chain((token('-').optional(), digit().skip_many1()))
+        /// Parses a number according to the JSON grammar, which doesn't allow
+        /// a leading `+`, leading zeros or special values.
+        pub fn $json<'a, I>() -> impl Parser<I, Output = $x> + Copy
+        where
+            I: Stream<Item = char, Range = &'a str>,
+        {
+            let nonzero = satisfy(|c| matches!(c, '1'..='9')).label("digit");
+            let integer = choice((
+                token('0').map(|_| ()),
+                chain((nonzero, digit().skip_many())).map(|_| ()),
+            ));
+            chain((token('-').optional(), integer, fraction_exponent()))
                 .recognize()
                 .from_str()
         }
     };
 }
 
-signed!(i8);
-signed!(i16);
-signed!(i32);
-signed!(i64);
-signed!(i128);
-signed!(isize);
+float!(f32, json_f32);
+float!(f64, json_f64);
@@ -10,14 +10,12 @@ where
 {
     type Output = Option<P::Output>;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         let (output, consumed) = self.0.parse_once_and_check_consumed(input);
-        if output.is_some() {
-            Some(output)
-        } else if consumed {
-            None
-        } else {
-            Some(None)
+        match output {
+            Ok(output) => Ok(Some(output)),
+            Err(error) if consumed || error.is_fatal() => Err(error),
+            Err(_) => Ok(None),
         }
     }
 }
@@ -27,14 +25,12 @@ where
     P: ParserMut<I>,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         let (output, consumed) = self.0.parse_mut_and_check_consumed(input);
-        if output.is_some() {
-            Some(output)
-        } else if consumed {
-            None
-        } else {
-            Some(None)
+        match output {
+            Ok(output) => Ok(Some(output)),
+            Err(error) if consumed || error.is_fatal() => Err(error),
+            Err(_) => Ok(None),
         }
     }
 }
@@ -44,14 +40,12 @@ where
     P: Parser<I>,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         let (output, consumed) = self.0.parse_and_check_consumed(input);
-        if output.is_some() {
-            Some(output)
-        } else if consumed {
-            None
-        } else {
-            Some(None)
+        match output {
+            Ok(output) => Ok(Some(output)),
+            Err(error) if consumed || error.is_fatal() => Err(error),
+            Err(_) => Ok(None),
         }
     }
 }
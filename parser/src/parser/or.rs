@@ -11,9 +11,11 @@ where
 {
     type Output = P::Output;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         match self.0.parse_once_and_check_consumed(input) {
-            (None, false) => self.1.parse_once(input),
+            (Err(error), false) if !error.is_fatal() => {
+                self.1.parse_once(input).map_err(|other| error.merge(other))
+            }
             (result, _) => result,
         }
     }
@@ -25,9 +27,11 @@ where
     Q: ParserMut<I, Output = P::Output>,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         match self.0.parse_mut_and_check_consumed(input) {
-            (None, false) => self.1.parse_mut(input),
+            (Err(error), false) if !error.is_fatal() => {
+                self.1.parse_mut(input).map_err(|other| error.merge(other))
+            }
             (result, _) => result,
         }
     }
@@ -39,9 +43,11 @@ where
     Q: Parser<I, Output = P::Output>,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         match self.0.parse_and_check_consumed(input) {
-            (None, false) => self.1.parse(input),
+            (Err(error), false) if !error.is_fatal() => {
+                self.1.parse(input).map_err(|other| error.merge(other))
+            }
             (result, _) => result,
         }
     }
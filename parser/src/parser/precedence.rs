@@ -0,0 +1,500 @@
+use super::*;
+use std::convert::Infallible;
+
+/// The associativity of an infix operator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right,
+    /// The operator cannot be chained with operators of the same precedence,
+    /// e.g. `a == b == c` is rejected.
+    None,
+}
+
+/// Operators that are parsed before an operand.
+pub trait PrefixOperator<I, O>
+where
+    I: Stream,
+{
+    type Op;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, Self::Op), Error<I>>;
+    fn apply(&self, op: Self::Op, operand: O) -> O;
+}
+
+/// Operators that are parsed between two operands.
+pub trait InfixOperator<I, O>
+where
+    I: Stream,
+{
+    type Op;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(Assoc, u32, Self::Op), Error<I>>;
+    fn apply(&self, left: O, op: Self::Op, right: O) -> O;
+}
+
+/// Operators that are parsed after an operand.
+pub trait PostfixOperator<I, O>
+where
+    I: Stream,
+{
+    type Op;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, Self::Op), Error<I>>;
+    fn apply(&self, operand: O, op: Self::Op) -> O;
+}
+
+impl<I, O> PrefixOperator<I, O> for ()
+where
+    I: Stream,
+{
+    type Op = Infallible;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, Self::Op), Error<I>> {
+        Err(Error::new(input.position()))
+    }
+
+    fn apply(&self, op: Self::Op, _: O) -> O {
+        match op {}
+    }
+}
+
+impl<I, O> InfixOperator<I, O> for ()
+where
+    I: Stream,
+{
+    type Op = Infallible;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(Assoc, u32, Self::Op), Error<I>> {
+        Err(Error::new(input.position()))
+    }
+
+    fn apply(&self, _: O, op: Self::Op, _: O) -> O {
+        match op {}
+    }
+}
+
+impl<I, O> PostfixOperator<I, O> for ()
+where
+    I: Stream,
+{
+    type Op = Infallible;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, Self::Op), Error<I>> {
+        Err(Error::new(input.position()))
+    }
+
+    fn apply(&self, _: O, op: Self::Op) -> O {
+        match op {}
+    }
+}
+
+/// The operator of one of two operator entries, remembering which entry it was
+/// parsed by.
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Tries `left` and then `right`, moving on to `right` only if `left` failed
+/// without consuming input.
+fn parse_either<L, R, I>(
+    input: &mut I,
+    left: impl FnOnce(&mut I) -> Result<L, Error<I>>,
+    right: impl FnOnce(&mut I) -> Result<R, Error<I>>,
+) -> Result<Either<L, R>, Error<I>>
+where
+    I: Stream,
+{
+    let position = input.position();
+    match left(input) {
+        Ok(output) => Ok(Either::Left(output)),
+        Err(error) if input.position() != position || error.is_fatal() => Err(error),
+        Err(error) => right(input)
+            .map(Either::Right)
+            .map_err(|other| error.merge(other)),
+    }
+}
+
+impl<L, R, I, O> PrefixOperator<I, O> for (L, R)
+where
+    L: PrefixOperator<I, O>,
+    R: PrefixOperator<I, O>,
+    I: Stream,
+{
+    type Op = Either<L::Op, R::Op>;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, Self::Op), Error<I>> {
+        match parse_either(
+            input,
+            |input| self.0.parse_operator(input),
+            |input| self.1.parse_operator(input),
+        )? {
+            Either::Left((precedence, op)) => Ok((precedence, Either::Left(op))),
+            Either::Right((precedence, op)) => Ok((precedence, Either::Right(op))),
+        }
+    }
+
+    fn apply(&self, op: Self::Op, operand: O) -> O {
+        match op {
+            Either::Left(op) => self.0.apply(op, operand),
+            Either::Right(op) => self.1.apply(op, operand),
+        }
+    }
+}
+
+impl<L, R, I, O> InfixOperator<I, O> for (L, R)
+where
+    L: InfixOperator<I, O>,
+    R: InfixOperator<I, O>,
+    I: Stream,
+{
+    type Op = Either<L::Op, R::Op>;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(Assoc, u32, Self::Op), Error<I>> {
+        match parse_either(
+            input,
+            |input| self.0.parse_operator(input),
+            |input| self.1.parse_operator(input),
+        )? {
+            Either::Left((assoc, precedence, op)) => Ok((assoc, precedence, Either::Left(op))),
+            Either::Right((assoc, precedence, op)) => Ok((assoc, precedence, Either::Right(op))),
+        }
+    }
+
+    fn apply(&self, left: O, op: Self::Op, right: O) -> O {
+        match op {
+            Either::Left(op) => self.0.apply(left, op, right),
+            Either::Right(op) => self.1.apply(left, op, right),
+        }
+    }
+}
+
+impl<L, R, I, O> PostfixOperator<I, O> for (L, R)
+where
+    L: PostfixOperator<I, O>,
+    R: PostfixOperator<I, O>,
+    I: Stream,
+{
+    type Op = Either<L::Op, R::Op>;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, Self::Op), Error<I>> {
+        match parse_either(
+            input,
+            |input| self.0.parse_operator(input),
+            |input| self.1.parse_operator(input),
+        )? {
+            Either::Left((precedence, op)) => Ok((precedence, Either::Left(op))),
+            Either::Right((precedence, op)) => Ok((precedence, Either::Right(op))),
+        }
+    }
+
+    fn apply(&self, operand: O, op: Self::Op) -> O {
+        match op {
+            Either::Left(op) => self.0.apply(operand, op),
+            Either::Right(op) => self.1.apply(operand, op),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Prefix<P, F> {
+    parser: P,
+    f: F,
+}
+
+impl<P, F, T, I, O> PrefixOperator<I, O> for Prefix<P, F>
+where
+    P: Parser<I, Output = (u32, T)>,
+    F: Fn(T, O) -> O,
+    I: Stream,
+{
+    type Op = T;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, T), Error<I>> {
+        self.parser.parse(input)
+    }
+
+    fn apply(&self, op: T, operand: O) -> O {
+        (self.f)(op, operand)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Infix<P, F> {
+    parser: P,
+    f: F,
+}
+
+impl<P, F, T, I, O> InfixOperator<I, O> for Infix<P, F>
+where
+    P: Parser<I, Output = (Assoc, u32, T)>,
+    F: Fn(O, T, O) -> O,
+    I: Stream,
+{
+    type Op = T;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(Assoc, u32, T), Error<I>> {
+        self.parser.parse(input)
+    }
+
+    fn apply(&self, left: O, op: T, right: O) -> O {
+        (self.f)(left, op, right)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Postfix<P, F> {
+    parser: P,
+    f: F,
+}
+
+impl<P, F, T, I, O> PostfixOperator<I, O> for Postfix<P, F>
+where
+    P: Parser<I, Output = (u32, T)>,
+    F: Fn(O, T) -> O,
+    I: Stream,
+{
+    type Op = T;
+
+    fn parse_operator(&self, input: &mut I) -> Result<(u32, T), Error<I>> {
+        self.parser.parse(input)
+    }
+
+    fn apply(&self, operand: O, op: T) -> O {
+        (self.f)(operand, op)
+    }
+}
+
+/// An expression parser that combines the outputs of an atom parser using
+/// prefix, infix and postfix operators, according to their precedence.
+///
+/// Operator parsers produce their precedence alongside a value that is passed
+/// to the corresponding fold function. Operators with a higher precedence bind
+/// more tightly. Every call to `prefix`, `infix` or `postfix` adds an entry to
+/// the operator table, and entries are tried in the order they were added.
+#[derive(Copy, Clone)]
+pub struct Precedence<A, Pre, In, Post> {
+    atom: A,
+    prefix: Pre,
+    infix: In,
+    postfix: Post,
+}
+
+impl<A, Pre, In, Post> Precedence<A, Pre, In, Post> {
+    pub fn prefix<P, F>(self, parser: P, f: F) -> Precedence<A, (Pre, Prefix<P, F>), In, Post> {
+        Precedence {
+            atom: self.atom,
+            prefix: (self.prefix, Prefix { parser, f }),
+            infix: self.infix,
+            postfix: self.postfix,
+        }
+    }
+
+    pub fn infix<P, F>(self, parser: P, f: F) -> Precedence<A, Pre, (In, Infix<P, F>), Post> {
+        Precedence {
+            atom: self.atom,
+            prefix: self.prefix,
+            infix: (self.infix, Infix { parser, f }),
+            postfix: self.postfix,
+        }
+    }
+
+    pub fn postfix<P, F>(self, parser: P, f: F) -> Precedence<A, Pre, In, (Post, Postfix<P, F>)> {
+        Precedence {
+            atom: self.atom,
+            prefix: self.prefix,
+            infix: self.infix,
+            postfix: (self.postfix, Postfix { parser, f }),
+        }
+    }
+
+    /// Parses an expression whose operators all have a precedence of at least
+    /// `min`.
+    fn expression<I>(&self, input: &mut I, min: u32) -> Result<A::Output, Error<I>>
+    where
+        A: Parser<I>,
+        Pre: PrefixOperator<I, A::Output>,
+        In: InfixOperator<I, A::Output>,
+        Post: PostfixOperator<I, A::Output>,
+        I: Stream,
+    {
+        let position = input.position();
+        let mut output = match self.prefix.parse_operator(input) {
+            Ok((precedence, op)) => {
+                let operand = self.expression(input, precedence)?;
+                self.prefix.apply(op, operand)
+            }
+            Err(error) if input.position() != position || error.is_fatal() => return Err(error),
+            Err(error) => self.atom.parse(input).map_err(|other| error.merge(other))?,
+        };
+
+        let mut non_assoc = None;
+        loop {
+            let checkpoint = *input;
+
+            match self.postfix.parse_operator(input) {
+                Ok((precedence, op)) if precedence >= min => {
+                    output = self.postfix.apply(output, op);
+                    continue;
+                }
+                Ok(_) => *input = checkpoint,
+                Err(error) if input.position() != checkpoint.position() || error.is_fatal() => {
+                    return Err(error)
+                }
+                Err(_) => {}
+            }
+
+            match self.infix.parse_operator(input) {
+                Ok((_, precedence, _)) if precedence < min => {
+                    *input = checkpoint;
+                    break;
+                }
+                Ok((Assoc::None, precedence, _)) if non_assoc == Some(precedence) => {
+                    return Err(Error::at(&checkpoint));
+                }
+                Ok((assoc, precedence, op)) => {
+                    let next = match assoc {
+                        Assoc::Right => precedence,
+                        Assoc::Left | Assoc::None => precedence.saturating_add(1),
+                    };
+                    let right = self.expression(input, next)?;
+                    output = self.infix.apply(output, op, right);
+                    non_assoc = (assoc == Assoc::None).then_some(precedence);
+                }
+                Err(error) if input.position() != checkpoint.position() || error.is_fatal() => {
+                    return Err(error)
+                }
+                Err(_) => break,
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+impl<A, Pre, In, Post, I> ParserOnce<I> for Precedence<A, Pre, In, Post>
+where
+    A: Parser<I>,
+    Pre: PrefixOperator<I, A::Output>,
+    In: InfixOperator<I, A::Output>,
+    Post: PostfixOperator<I, A::Output>,
+    I: Stream,
+{
+    type Output = A::Output;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<A, Pre, In, Post, I> ParserMut<I> for Precedence<A, Pre, In, Post>
+where
+    A: Parser<I>,
+    Pre: PrefixOperator<I, A::Output>,
+    In: InfixOperator<I, A::Output>,
+    Post: PostfixOperator<I, A::Output>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<A, Pre, In, Post, I> Parser<I> for Precedence<A, Pre, In, Post>
+where
+    A: Parser<I>,
+    Pre: PrefixOperator<I, A::Output>,
+    In: InfixOperator<I, A::Output>,
+    Post: PostfixOperator<I, A::Output>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.expression(input, 0)
+    }
+}
+
+/// Starts building an expression parser out of `atom`, to which operators can
+/// be added with `prefix`, `infix` and `postfix`.
+pub fn precedence<A>(atom: A) -> Precedence<A, (), (), ()> {
+    Precedence {
+        atom,
+        prefix: (),
+        infix: (),
+        postfix: (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+
+    #[opaque]
+    fn expr<'a>() -> impl Parser<&'a str, Output = String> {
+        let atom = choice((
+            satisfy(char::is_alphanumeric).map(String::from),
+            expr().between(token('('), token(')')),
+        ));
+        let infix = choice((
+            token('+').map(|c| (Assoc::Left, 1, c)),
+            token('-').map(|c| (Assoc::Left, 1, c)),
+            token('*').map(|c| (Assoc::Left, 2, c)),
+            token('^').map(|c| (Assoc::Right, 4, c)),
+            token('=').map(|c| (Assoc::None, 0, c)),
+        ));
+
+        precedence(atom)
+            .prefix(token('-').map(|c| (3, c)), |op, x| format!("({}{})", op, x))
+            .infix(infix, |l, op, r| format!("({} {} {})", l, op, r))
+            .postfix(token('!').map(|c| (5, c)), |x, op| format!("({}{})", x, op))
+    }
+
+    #[test]
+    fn test_precedence() {
+        let parse = |input| expr().parse_to_end(input);
+        assert_eq!(parse("a"), Ok("a".to_string()));
+        assert_eq!(parse("a+b*c"), Ok("(a + (b * c))".to_string()));
+        assert_eq!(parse("a-b-c"), Ok("((a - b) - c)".to_string()));
+        assert_eq!(parse("a^b^c"), Ok("(a ^ (b ^ c))".to_string()));
+        assert_eq!(parse("-a*b"), Ok("((-a) * b)".to_string()));
+        assert_eq!(parse("-a^b"), Ok("(-(a ^ b))".to_string()));
+        assert_eq!(parse("-a!"), Ok("(-(a!))".to_string()));
+        assert_eq!(parse("(a+b)*c!"), Ok("((a + b) * (c!))".to_string()));
+        assert_eq!(parse("a+b=c"), Ok("((a + b) = c)".to_string()));
+        assert!(parse("a=b=c").is_err());
+        assert!(parse("a+").is_err());
+    }
+
+    #[test]
+    fn test_precedence_atoms() {
+        let parser = precedence(super::i32()).infix(
+            choice((
+                token('+').map(|_| (Assoc::Left, 1, i32::wrapping_add as fn(i32, i32) -> i32)),
+                token('*').map(|_| (Assoc::Left, 2, i32::wrapping_mul as fn(i32, i32) -> i32)),
+            )),
+            |l, op: fn(i32, i32) -> i32, r| op(l, r),
+        );
+        assert_eq!(parser.parse_to_end("1+2*3+4"), Ok(11));
+
+        let error = parser.parse_to_end("x").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'x', expected digit");
+    }
+
+    #[test]
+    fn test_precedence_table() {
+        let parser = precedence(digit())
+            .prefix(token('-').map(|_| (3, ())), |(), x: u32| x.wrapping_neg())
+            .infix(token('+').map(|_| (Assoc::Left, 1, ())), |l, (), r| l + r)
+            .infix(token('*').map(|_| (Assoc::Left, 2, ())), |l, (), r| l * r)
+            .infix(
+                token('-').map(|_| (Assoc::Left, u32::MAX, ())),
+                |l, (), r| l - r,
+            )
+            .postfix(token('!').map(|_| (4, ())), |x, ()| (1..=x).product());
+        assert_eq!(parser.parse_to_end("1+2"), Ok(3));
+        assert_eq!(parser.parse_to_end("1+2*3!"), Ok(13));
+        assert_eq!(parser.parse_to_end("9-3*2"), Ok(12));
+        assert_eq!(parser.parse_to_end("--2+3"), Ok(5));
+    }
+}
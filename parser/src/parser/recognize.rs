@@ -10,11 +10,15 @@ where
 {
     type Output = I::Range;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        let start = input.position();
-        self.0.parse_once(input)?;
-        let end = input.position();
-        Some(unsafe { I::between(start, end) })
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let checkpoint = *input;
+        checkpoint.retain();
+        let result = self
+            .0
+            .parse_once(input)
+            .map(|_| input.slice_since(&checkpoint));
+        checkpoint.release();
+        result
     }
 }
 
@@ -23,11 +27,15 @@ where
     P: ParserMut<I>,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        let start = input.position();
-        self.0.parse_mut(input)?;
-        let end = input.position();
-        Some(unsafe { I::between(start, end) })
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let checkpoint = *input;
+        checkpoint.retain();
+        let result = self
+            .0
+            .parse_mut(input)
+            .map(|_| input.slice_since(&checkpoint));
+        checkpoint.release();
+        result
     }
 }
 
@@ -36,11 +44,12 @@ where
     P: Parser<I>,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        let start = input.position();
-        self.0.parse(input)?;
-        let end = input.position();
-        Some(unsafe { I::between(start, end) })
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let checkpoint = *input;
+        checkpoint.retain();
+        let result = self.0.parse(input).map(|_| input.slice_since(&checkpoint));
+        checkpoint.release();
+        result
     }
 }
 
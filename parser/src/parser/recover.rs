@@ -0,0 +1,180 @@
+use super::*;
+
+pub struct Recover<'a, P, R, I>
+where
+    I: Stream,
+{
+    parser: P,
+    strategy: R,
+    collector: &'a Collector<I>,
+}
+
+impl<P, R, I> Copy for Recover<'_, P, R, I>
+where
+    P: Copy,
+    R: Copy,
+    I: Stream,
+{
+}
+
+impl<P, R, I> Clone for Recover<'_, P, R, I>
+where
+    P: Clone,
+    R: Clone,
+    I: Stream,
+{
+    fn clone(&self) -> Self {
+        Recover {
+            parser: self.parser.clone(),
+            strategy: self.strategy.clone(),
+            collector: self.collector,
+        }
+    }
+}
+
+impl<P, R, I> ParserOnce<I> for Recover<'_, P, R, I>
+where
+    P: ParserOnce<I>,
+    R: ParserOnce<I, Output = P::Output>,
+    I: Stream,
+{
+    type Output = P::Output;
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let start = *input;
+        let error = match self.parser.parse_once(input) {
+            Ok(output) => return Ok(output),
+            Err(error) if !error.is_incomplete() => error,
+            Err(error) => return Err(error),
+        };
+        let failed = std::mem::replace(input, start);
+        match self.strategy.parse_once(input) {
+            Ok(output) => {
+                self.collector.push(error);
+                Ok(output)
+            }
+            Err(incomplete) if incomplete.is_incomplete() => {
+                *input = start;
+                Err(incomplete)
+            }
+            Err(_) => {
+                *input = failed;
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<P, R, I> ParserMut<I> for Recover<'_, P, R, I>
+where
+    P: ParserMut<I>,
+    R: ParserMut<I, Output = P::Output>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        Recover {
+            parser: &mut self.parser,
+            strategy: &mut self.strategy,
+            collector: self.collector,
+        }
+        .parse_once(input)
+    }
+}
+
+impl<P, R, I> Parser<I> for Recover<'_, P, R, I>
+where
+    P: Parser<I>,
+    R: Parser<I, Output = P::Output>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        Recover {
+            parser: &self.parser,
+            strategy: &self.strategy,
+            collector: self.collector,
+        }
+        .parse_once(input)
+    }
+}
+
+pub fn recover<'a, P, R, I>(
+    parser: P,
+    strategy: R,
+    collector: &'a Collector<I>,
+) -> Recover<'a, P, R, I>
+where
+    I: Stream,
+{
+    Recover {
+        parser,
+        strategy,
+        collector,
+    }
+}
+
+/// Skips at least one token, and then tokens up to the point where `sync`
+/// would succeed, or up to the end of the input, without consuming the input
+/// matched by `sync`. Fails at the end of the input, so that a recovery that
+/// uses it always makes progress, and with an incomplete error at the end of a
+/// partial stream.
+///
+/// When `sync` is part of the element that is being recovered, rather than a
+/// separator that is parsed afterwards, it has to be consumed by the strategy
+/// as well, e.g. `chain((skip_until(token(';')), token(';')))`, or else the
+/// next element fails on it right away.
+pub fn skip_until<P, I>(sync: P) -> impl Parser<I, Output = ()>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    from_fn(move |input: &mut I| {
+        any().parse(input)?;
+        loop {
+            if input.is_empty() {
+                if input.is_partial() {
+                    return Err(Error::incomplete(input.position(), 1));
+                }
+                return Ok(());
+            }
+            match sync.parse(&mut { *input }) {
+                Ok(_) => return Ok(()),
+                Err(error) if error.is_incomplete() => return Err(error),
+                Err(_) => input.uncons_map(Some),
+            };
+        }
+    })
+}
+
+/// Produces the output of `f` without consuming any input, to stand in for a
+/// missing element.
+pub fn insert_with<F, I, O>(f: F) -> impl Parser<I, Output = O> + Copy
+where
+    F: Fn() -> O + Copy,
+    I: Stream,
+{
+    from_fn(move |_: &mut I| Ok(f()))
+}
+
+/// Skips an `open` token and everything up to and including its matching
+/// `close` token, taking nested pairs of delimiters into account.
+pub fn skip_balanced<I>(open: I::Item, close: I::Item) -> impl Parser<I, Output = ()> + Copy
+where
+    I: Stream,
+    I::Item: PartialEq,
+{
+    from_fn(move |input: &mut I| {
+        token(open).parse(input)?;
+        let mut depth = 1;
+        while depth > 0 {
+            let t = any()
+                .parse(input)
+                .map_err(|error| error.with_expected(Info::Token(close)))?;
+            if t == open {
+                depth += 1;
+            } else if t == close {
+                depth -= 1;
+            }
+        }
+        Ok(())
+    })
+}
@@ -26,7 +26,7 @@ where
 {
     type Output = I::Item;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         satisfy_map_once(move |x| if (self.f)(x) { Some(x) } else { None }).parse_once(input)
     }
 }
@@ -36,7 +36,7 @@ where
     F: FnMut(I::Item) -> bool,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         satisfy_map_mut(move |x| if (self.f)(x) { Some(x) } else { None }).parse_mut(input)
     }
 }
@@ -46,7 +46,7 @@ where
     F: Fn(I::Item) -> bool,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         satisfy_map(move |x| if (self.f)(x) { Some(x) } else { None }).parse(input)
     }
 }
@@ -26,8 +26,8 @@ where
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        input.uncons_map(self.f)
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        input.uncons_map(self.f).ok_or_else(|| Error::at(input))
     }
 }
 
@@ -36,8 +36,10 @@ where
     F: FnMut(I::Item) -> Option<O>,
     I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        input.uncons_map(&mut self.f)
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        input
+            .uncons_map(&mut self.f)
+            .ok_or_else(|| Error::at(input))
     }
 }
 
@@ -46,8 +48,8 @@ where
     F: Fn(I::Item) -> Option<O>,
     I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
-        input.uncons_map(&self.f)
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        input.uncons_map(&self.f).ok_or_else(|| Error::at(input))
     }
 }
 
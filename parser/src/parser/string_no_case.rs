@@ -0,0 +1,192 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct StringNoCase<'a> {
+    string: &'a str,
+    unicode: bool,
+}
+
+impl StringNoCase<'_> {
+    fn matches(&self, expected: char, c: char) -> bool {
+        if self.unicode {
+            expected == c || expected.to_lowercase().eq(c.to_lowercase())
+        } else {
+            expected.eq_ignore_ascii_case(&c)
+        }
+    }
+}
+
+impl<I> ParserOnce<I> for StringNoCase<'_>
+where
+    I: Stream<Item = char>,
+{
+    type Output = ();
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<I> ParserMut<I> for StringNoCase<'_>
+where
+    I: Stream<Item = char>,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse(input)
+    }
+}
+
+impl<I> Parser<I> for StringNoCase<'_>
+where
+    I: Stream<Item = char>,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let mut chars = self.string.chars();
+        while let Some(expected) = chars.next() {
+            if input
+                .uncons_map(|c| self.matches(expected, c).then_some(()))
+                .is_none()
+            {
+                let error = Error::at(input);
+                return Err(if error.is_incomplete() {
+                    Error::incomplete(input.position(), 1 + chars.count())
+                } else {
+                    error.with_expected(Info::Token(expected))
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses `string`, ignoring differences in ASCII case.
+pub fn string_no_case<I>(string: &str) -> impl Parser<I, Output = ()> + Copy + '_
+where
+    I: Stream<Item = char>,
+{
+    StringNoCase {
+        string,
+        unicode: false,
+    }
+}
+
+/// Parses `string`, comparing characters by their lowercase mappings.
+pub fn string_no_case_unicode<I>(string: &str) -> impl Parser<I, Output = ()> + Copy + '_
+where
+    I: Stream<Item = char>,
+{
+    StringNoCase {
+        string,
+        unicode: true,
+    }
+}
+
+fn identifier_char<I>() -> impl Parser<I, Output = char> + Copy
+where
+    I: Stream<Item = char>,
+{
+    satisfy(|c: char| c == '_' || c.is_alphanumeric())
+}
+
+#[derive(Copy, Clone)]
+pub struct Keyword<P>(P);
+
+impl<P, I> ParserOnce<I> for Keyword<P>
+where
+    P: ParserOnce<I>,
+    I: Stream<Item = char>,
+{
+    type Output = ();
+
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let copy = *input;
+        let result = self
+            .0
+            .parse_once(input)
+            .and_then(|_| not_followed_by(identifier_char()).parse(input));
+        result.inspect_err(|_| {
+            *input = copy;
+        })
+    }
+}
+
+impl<P, I> ParserMut<I> for Keyword<P>
+where
+    P: ParserMut<I>,
+    I: Stream<Item = char>,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        Keyword(&mut self.0).parse_once(input)
+    }
+}
+
+impl<P, I> Parser<I> for Keyword<P>
+where
+    P: Parser<I>,
+    I: Stream<Item = char>,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        Keyword(&self.0).parse_once(input)
+    }
+}
+
+/// Parses `word` unless it is immediately followed by an identifier character,
+/// in which case no input is consumed.
+pub fn keyword<I>(word: &str) -> impl Parser<I, Output = ()> + Copy + '_
+where
+    I: Stream<Item = char>,
+{
+    Keyword(String(word))
+}
+
+/// Like `keyword`, but ignores differences in ASCII case.
+pub fn keyword_no_case<I>(word: &str) -> impl Parser<I, Output = ()> + Copy + '_
+where
+    I: Stream<Item = char>,
+{
+    Keyword(StringNoCase {
+        string: word,
+        unicode: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_no_case() {
+        let mut input = "SeLeCt *";
+        assert_eq!(
+            string_no_case("select").recognize().parse(&mut input),
+            Ok("SeLeCt")
+        );
+        assert_eq!(input, " *");
+
+        let error = string_no_case("from").parse_to_end("FRUM").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'U', expected 'o'");
+        assert!(string_no_case("straße").parse_to_end("STRASSE").is_err());
+        assert!(string_no_case("é").parse_to_end("É").is_err());
+        assert!(string_no_case_unicode("éΣ").parse_to_end("Éσ").is_ok());
+    }
+
+    #[test]
+    fn test_keyword() {
+        let mut input = "select x";
+        assert!(keyword("select").parse(&mut input).is_ok());
+        assert_eq!(input, " x");
+
+        let mut input = "selection";
+        assert!(keyword("select").parse(&mut input).is_err());
+        assert_eq!(input, "selection");
+        assert!(keyword("select").parse_to_end("select_1").is_err());
+        assert!(keyword("select").parse_to_end("select").is_ok());
+
+        let statement = choice((
+            keyword_no_case("select").map(|_| None),
+            identifier_char().skip_many1().recognize().map(Some),
+        ));
+        assert_eq!(statement.parse_to_end("SELECT"), Ok(None));
+        assert_eq!(statement.parse_to_end("selected"), Ok(Some("selected")));
+    }
+}
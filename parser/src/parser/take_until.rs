@@ -0,0 +1,65 @@
+use super::*;
+
+#[derive(Copy, Clone)]
+pub struct TakeUntil<P>(P);
+
+/// Skips items until `end` would succeed, and returns the skipped range. Fails
+/// with the error of `end` at the end of the input, or if that error is fatal.
+fn take<O, I>(
+    input: &mut I,
+    mut end: impl FnMut(&mut I) -> Result<O, Error<I>>,
+) -> Result<I::Range, Error<I>>
+where
+    I: Stream,
+{
+    let checkpoint = *input;
+    checkpoint.retain();
+    let result = loop {
+        match end(&mut { *input }) {
+            Ok(_) => break Ok(input.slice_since(&checkpoint)),
+            Err(error) if input.is_empty() || error.is_fatal() => break Err(error),
+            Err(_) => input.uncons_map(Some),
+        };
+    };
+    checkpoint.release();
+    result
+}
+
+impl<P, I> ParserOnce<I> for TakeUntil<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    type Output = I::Range;
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<P, I> ParserMut<I> for TakeUntil<P>
+where
+    P: ParserMut<I>,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        take(input, |input| self.0.parse_mut(input))
+    }
+}
+
+impl<P, I> Parser<I> for TakeUntil<P>
+where
+    P: Parser<I>,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        take(input, |input| self.0.parse(input))
+    }
+}
+
+/// Consumes tokens up to the point where `end` would succeed, without consuming
+/// the input matched by `end`, and returns them. Fails if `end` doesn't succeed
+/// before the end of the input.
+pub fn take_until<P>(end: P) -> TakeUntil<P> {
+    TakeUntil(end)
+}
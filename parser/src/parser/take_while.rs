@@ -0,0 +1,168 @@
+use super::*;
+
+pub struct TakeWhile<F, I> {
+    f: F,
+    min: usize,
+    max: usize,
+    _marker: PhantomData<I>,
+}
+
+impl<F, I> Copy for TakeWhile<F, I> where F: Copy {}
+
+impl<F, I> Clone for TakeWhile<F, I>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            f: self.f.clone(),
+            min: self.min,
+            max: self.max,
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn take<I>(
+    input: &mut I,
+    min: usize,
+    max: usize,
+    f: impl FnMut(I::Item) -> bool,
+) -> Result<I::Range, Error<I>>
+where
+    I: Stream,
+{
+    let checkpoint = *input;
+    checkpoint.retain();
+    let count = input.skip_while(max, f);
+    let result = if count < max && input.is_empty() && input.is_partial() {
+        let needed = min.saturating_sub(count).max(1);
+        Err(Error::incomplete(input.position(), needed))
+    } else if count < min {
+        let error = Error::at(input).with_label(match min {
+            1 => "matching item".to_string(),
+            _ => format!("at least {} matching items", min),
+        });
+        *input = checkpoint;
+        Err(error)
+    } else {
+        Ok(input.slice_since(&checkpoint))
+    };
+    checkpoint.release();
+    result
+}
+
+impl<F, I> ParserOnce<I> for TakeWhile<F, I>
+where
+    F: FnMut(I::Item) -> bool,
+    I: Stream,
+{
+    type Output = I::Range;
+
+    fn parse_once(mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        self.parse_mut(input)
+    }
+}
+
+impl<F, I> ParserMut<I> for TakeWhile<F, I>
+where
+    F: FnMut(I::Item) -> bool,
+    I: Stream,
+{
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        take(input, self.min, self.max, &mut self.f)
+    }
+}
+
+impl<F, I> Parser<I> for TakeWhile<F, I>
+where
+    F: Fn(I::Item) -> bool,
+    I: Stream,
+{
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        take(input, self.min, self.max, &self.f)
+    }
+}
+
+/// Consumes the longest prefix of items that satisfy `f`, and returns it.
+pub fn take_while<F, I>(f: F) -> TakeWhile<F, I>
+where
+    F: Fn(I::Item) -> bool,
+    I: Stream,
+{
+    take_while_m_n(0, usize::MAX, f)
+}
+
+/// Like `take_while`, but fails unless at least one item satisfies `f`.
+pub fn take_while1<F, I>(f: F) -> TakeWhile<F, I>
+where
+    F: Fn(I::Item) -> bool,
+    I: Stream,
+{
+    take_while_m_n(1, usize::MAX, f)
+}
+
+/// Consumes at most `n` items that satisfy `f`, and returns them. Fails without
+/// consuming any input if fewer than `m` items satisfy `f`.
+pub fn take_while_m_n<F, I>(m: usize, n: usize, f: F) -> TakeWhile<F, I>
+where
+    F: Fn(I::Item) -> bool,
+    I: Stream,
+{
+    TakeWhile {
+        f,
+        min: m,
+        max: n,
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_while() {
+        let identifier = take_while1(|c: char| c.is_alphanumeric() || c == '_');
+        let mut input = "foo_bär1 = 2";
+        assert_eq!(identifier.parse(&mut input), Ok("foo_bär1"));
+        assert_eq!(input, " = 2");
+        let error = identifier.parse(&mut input).unwrap_err();
+        assert_eq!(error.unexpected(), Some(&Info::Token(' ')));
+
+        let mut input = " = 2";
+        assert_eq!(take_while(|c| c == 'x').parse(&mut input), Ok(""));
+        assert_eq!(input, " = 2");
+
+        let hex = take_while_m_n(2, 4, |c: char| c.is_ascii_hexdigit());
+        assert_eq!(hex.parse_partial("abcdef"), Ok("abcd"));
+        assert_eq!(hex.parse_partial("ab!"), Ok("ab"));
+        let mut input = "a!";
+        let error = hex.parse(&mut input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected '!', expected at least 2 matching items"
+        );
+        assert_eq!(input, "a!");
+        let parser = choice((hex, string("a!").recognize()));
+        assert_eq!(parser.parse_to_end("a!"), Ok("a!"));
+
+        let digits = take_while1(|b: u8| b.is_ascii_digit());
+        let mut input = Bytes::new(b"123abc");
+        assert_eq!(digits.parse(&mut input), Ok(&b"123"[..]));
+        assert_eq!(input, Bytes::new(b"abc"));
+
+        let mut input = Located::new("ab\ncd");
+        let line = take_while(|c| c != '\n');
+        assert_eq!(line.parse(&mut input), Ok("ab"));
+        assert_eq!(input.position().column(), 3);
+
+        let digits = take_while1(|b: u8| b.is_ascii_digit());
+        let error = digits
+            .parse(&mut Partial::new(Bytes::new(b"12")))
+            .unwrap_err();
+        assert_eq!(error.needed(), Some(1));
+        let mut input = Partial::new(Bytes::new(b"12;"));
+        assert_eq!(digits.parse(&mut input), Ok(&b"12"[..]));
+    }
+}
@@ -27,11 +27,22 @@ where
 {
     type Output = ();
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
-        self.iter
-            .into_iter()
-            .map(|item| input.uncons_map(|t| if t == item { Some(()) } else { None }))
-            .collect()
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+        let mut iter = self.iter.into_iter();
+        while let Some(item) = iter.next() {
+            if input
+                .uncons_map(|t| if t == item { Some(()) } else { None })
+                .is_none()
+            {
+                let error = Error::at(input);
+                return Err(if error.is_incomplete() {
+                    Error::incomplete(input.position(), 1 + iter.count())
+                } else {
+                    error.with_expected(Info::Token(item))
+                });
+            }
+        }
+        Ok(())
     }
 }
 
@@ -41,7 +52,7 @@ where
     I: Stream,
     I::Item: PartialEq,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_once(input)
     }
 }
@@ -52,7 +63,7 @@ where
     I: Stream,
     I::Item: PartialEq,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parse_once(input)
     }
 }
@@ -0,0 +1,399 @@
+use super::*;
+use crate::error::Expected;
+use std::collections::BTreeSet;
+use std::fmt::{self, Write};
+use std::ops::Range;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+const TAB_WIDTH: usize = 4;
+
+struct Label {
+    span: Range<usize>,
+    message: String,
+    primary: bool,
+}
+
+/// A diagnostic that renders a message together with the lines of the source
+/// it refers to, in the style of rustc.
+pub struct Report<'a> {
+    file_name: &'a str,
+    source: &'a str,
+    message: String,
+    labels: Vec<Label>,
+    /// The location shown in the header, if it is known instead of computed
+    /// from the primary label.
+    location: Option<Location>,
+    color: bool,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(file_name: &'a str, source: &'a str, message: impl Into<String>) -> Self {
+        Report {
+            file_name,
+            source,
+            message: message.into(),
+            labels: Vec::new(),
+            location: None,
+            color: false,
+        }
+    }
+
+    /// Builds a report for an error produced by parsing `Located::new(source)`.
+    pub fn from_error<I>(file_name: &'a str, source: &'a str, error: &Error<I>) -> Self
+    where
+        I: Stream<Item = char, Position = Location>,
+    {
+        let offset = error.position().offset();
+        let (message, width) = match error.unexpected() {
+            Some(info @ Info::Token(c)) => (format!("unexpected {}", info), c.len_utf8()),
+            Some(info) => (format!("unexpected {}", info), 0),
+            None => ("invalid input".to_string(), 0),
+        };
+
+        let mut report = Report::new(file_name, source, message).with_label(
+            offset..offset + width,
+            Expected(error.expected()).to_string(),
+        );
+        report.location = Some(*error.position());
+        report
+    }
+
+    /// Points at the part of the source that caused the error.
+    pub fn with_label(self, span: Range<usize>, message: impl Into<String>) -> Self {
+        self.label(span, message.into(), true)
+    }
+
+    /// Points at a part of the source that provides additional context.
+    pub fn with_secondary_label(self, span: Range<usize>, message: impl Into<String>) -> Self {
+        self.label(span, message.into(), false)
+    }
+
+    /// Adds a label, after moving both ends of its span back to the nearest
+    /// character boundary in the source.
+    fn label(mut self, span: Range<usize>, message: String, primary: bool) -> Self {
+        let boundary = |mut offset: usize| {
+            offset = offset.min(self.source.len());
+            while !self.source.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            offset
+        };
+        self.labels.push(Label {
+            span: boundary(span.start)..boundary(span.end),
+            message,
+            primary,
+        });
+        self
+    }
+
+    /// Enables ANSI color codes in the rendered output.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn lines(&self) -> Vec<&'a str> {
+        self.source
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect()
+    }
+
+    /// The line index and display column of the character at `offset`.
+    fn locate(&self, offset: usize) -> (usize, usize) {
+        let before = &self.source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count();
+        (line, display_width(&before[line_start..]))
+    }
+
+    fn annotations(&self) -> Vec<Annotation<'_>> {
+        self.labels
+            .iter()
+            .map(|label| {
+                let start = self.locate(label.span.start);
+                let end = if label.span.end > label.span.start {
+                    let last = self.source[..label.span.end]
+                        .char_indices()
+                        .next_back()
+                        .map_or(0, |(i, _)| i);
+                    self.locate(last.max(label.span.start))
+                } else {
+                    start
+                };
+                Annotation { label, start, end }
+            })
+            .collect()
+    }
+
+    fn paint(&self, out: &mut String, style: &str, text: impl fmt::Display) {
+        if self.color {
+            let _ = write!(out, "{}{}{}", style, text, RESET);
+        } else {
+            let _ = write!(out, "{}", text);
+        }
+    }
+}
+
+struct Annotation<'a> {
+    label: &'a Label,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Annotation<'_> {
+    fn is_multiline(&self) -> bool {
+        self.start.0 != self.end.0
+    }
+
+    fn marker(&self) -> char {
+        if self.label.primary {
+            '^'
+        } else {
+            '-'
+        }
+    }
+
+    fn style(&self) -> &'static str {
+        if self.label.primary {
+            RED
+        } else {
+            BLUE
+        }
+    }
+}
+
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum()
+}
+
+/// A single line of annotations underneath a source line.
+#[derive(Default)]
+struct Row {
+    cells: Vec<(char, &'static str)>,
+    message: Option<(String, &'static str)>,
+}
+
+impl Row {
+    fn put(&mut self, column: usize, c: char, style: &'static str) {
+        if self.cells.len() <= column {
+            self.cells.resize(column + 1, (' ', ""));
+        }
+        self.cells[column] = (c, style);
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self.lines();
+        let annotations = self.annotations();
+        let multiline: Vec<&Annotation<'_>> =
+            annotations.iter().filter(|a| a.is_multiline()).collect();
+        let gutter = 2 * multiline.len();
+
+        let shown: BTreeSet<usize> = annotations
+            .iter()
+            .flat_map(|a| a.start.0..=a.end.0)
+            .filter(|&line| line < lines.len())
+            .collect();
+        let width = shown
+            .iter()
+            .next_back()
+            .map_or(1, |&line| (line + 1).to_string().len());
+
+        let mut out = String::new();
+        self.paint(&mut out, RED, "error");
+        self.paint(&mut out, BOLD, format_args!(": {}", self.message));
+        out.push('\n');
+
+        let primary = annotations
+            .iter()
+            .find(|a| a.label.primary)
+            .or_else(|| annotations.first());
+        if let Some(location) = self.location {
+            self.paint(&mut out, BLUE, format_args!("{:w$}--> ", "", w = width));
+            let _ = writeln!(out, "{}:{}", self.file_name, location);
+        } else if let Some(primary) = primary {
+            let offset = primary.label.span.start;
+            let line_start = self.source[..offset].rfind('\n').map_or(0, |i| i + 1);
+            let column = self.source[line_start..offset].chars().count() + 1;
+            self.paint(&mut out, BLUE, format_args!("{:w$}--> ", "", w = width));
+            let _ = writeln!(out, "{}:{}:{}", self.file_name, primary.start.0 + 1, column);
+        } else {
+            let _ = writeln!(out, "{:w$}--> {}", "", self.file_name, w = width);
+        }
+
+        let empty_gutter = format!("{:w$} |", "", w = width);
+        self.paint(&mut out, BLUE, &empty_gutter);
+        out.push('\n');
+
+        let mut previous = None;
+        for &line in &shown {
+            if previous.is_some_and(|previous| line > previous + 1) {
+                self.paint(&mut out, BLUE, "...");
+                out.push('\n');
+            }
+            previous = Some(line);
+
+            self.paint(
+                &mut out,
+                BLUE,
+                format_args!("{:>w$} | ", line + 1, w = width),
+            );
+            for a in &multiline {
+                let active = a.start.0 < line && line <= a.end.0;
+                self.paint(&mut out, a.style(), if active { "| " } else { "  " });
+            }
+            out.push_str(&lines[line].replace('\t', &" ".repeat(TAB_WIDTH)));
+            out.push('\n');
+
+            let mut ended = vec![false; multiline.len()];
+            let mut rows = Vec::new();
+
+            let active_cells = |row: &mut Row, ended: &[bool]| {
+                for (i, a) in multiline.iter().enumerate() {
+                    if a.start.0 < line && line <= a.end.0 && !ended[i] {
+                        row.put(2 * i, '|', a.style());
+                    }
+                }
+            };
+
+            for (i, a) in multiline.iter().enumerate() {
+                if a.end.0 == line {
+                    let mut row = Row::default();
+                    active_cells(&mut row, &ended);
+                    for column in 2 * i + 1..gutter + a.end.1 {
+                        row.put(column, '_', a.style());
+                    }
+                    row.put(gutter + a.end.1, a.marker(), a.style());
+                    row.message = Some((a.label.message.clone(), a.style()));
+                    rows.push(row);
+                    ended[i] = true;
+                }
+            }
+
+            for a in annotations
+                .iter()
+                .filter(|a| !a.is_multiline() && a.start.0 == line)
+            {
+                let mut row = Row::default();
+                active_cells(&mut row, &ended);
+                for column in a.start.1..=a.end.1 {
+                    row.put(gutter + column, a.marker(), a.style());
+                }
+                row.message = Some((a.label.message.clone(), a.style()));
+                rows.push(row);
+            }
+
+            for (i, a) in multiline.iter().enumerate() {
+                if a.start.0 == line {
+                    let mut row = Row::default();
+                    active_cells(&mut row, &ended);
+                    for column in 2 * i + 1..gutter + a.start.1 {
+                        row.put(column, '_', a.style());
+                    }
+                    row.put(gutter + a.start.1, a.marker(), a.style());
+                    rows.push(row);
+                }
+            }
+
+            for row in rows {
+                self.paint(&mut out, BLUE, &empty_gutter);
+                out.push(' ');
+                for (c, style) in row.cells {
+                    self.paint(&mut out, style, c);
+                }
+                if let Some((message, style)) = row.message.filter(|(m, _)| !m.is_empty()) {
+                    self.paint(&mut out, style, format_args!(" {}", message));
+                }
+                out.push('\n');
+            }
+        }
+
+        f.write_str(out.trim_end_matches('\n'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let source = "ab\nce";
+        let error = chain((string("ab"), token('\n'), string("cd")))
+            .parse_to_end(Located::new(source))
+            .unwrap_err();
+
+        let report = Report::from_error("input.txt", source, &error);
+        assert_eq!(
+            report.to_string(),
+            "\
+error: unexpected 'e'
+ --> input.txt:2:2
+  |
+2 | ce
+  |  ^ expected 'd'"
+        );
+
+        let source = "\tx";
+        let error = chain((token('\t'), token('y')))
+            .parse_to_end(Located::new(source))
+            .unwrap_err();
+        assert_eq!(error.position().to_string(), "1:5");
+        let report = Report::from_error("input.txt", source, &error);
+        assert_eq!(
+            report.to_string(),
+            "\
+error: unexpected 'x'
+ --> input.txt:1:5
+  |
+1 |     x
+  |     ^ expected 'y'"
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let source = "let x = {\n    1\n};\nx(2, 3)";
+        let report = Report::new("main.rs", source, "mismatched types")
+            .with_label(8..17, "block")
+            .with_secondary_label(19..20, "call")
+            .with_label(21..22, "argument");
+        assert_eq!(
+            report.to_string(),
+            "\
+error: mismatched types
+ --> main.rs:1:9
+  |
+1 |   let x = {
+  |  _________^
+2 | |     1
+3 | | };
+  | |_^ block
+4 |   x(2, 3)
+  |   - call
+  |     ^ argument"
+        );
+
+        let colored = report.with_color(true).to_string();
+        assert!(colored.starts_with("\x1b[1;31merror\x1b[0m"));
+
+        let report = Report::new("input.txt", "é", "invalid").with_label(1..2, "here");
+        assert_eq!(
+            report.to_string(),
+            "\
+error: invalid
+ --> input.txt:1:1
+  |
+1 | é
+  | ^ here"
+        );
+    }
+}
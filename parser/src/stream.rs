@@ -1,3 +1,13 @@
+mod bytes;
+mod located;
+mod partial;
+mod read;
+
+pub use bytes::Bytes;
+pub use located::{Located, Location};
+pub use partial::Partial;
+pub use read::{ReadBuffer, ReadStream};
+
 pub trait Stream: Copy {
     type Item: Copy;
     type Position: Ord;
@@ -6,10 +16,37 @@ pub trait Stream: Copy {
     fn is_empty(&self) -> bool;
     fn position(&self) -> Self::Position;
     fn uncons_map<O>(&mut self, f: impl FnOnce(Self::Item) -> Option<O>) -> Option<O>;
-    unsafe fn between(start: Self::Position, end: Self::Position) -> Self::Range;
+    /// The part of the input that was consumed since `checkpoint`, an earlier
+    /// copy of this stream.
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range;
+
+    /// Whether more input may follow the end of this stream, in which case
+    /// running out of input is not the same as reaching the end of the input.
+    fn is_partial(&self) -> bool {
+        false
+    }
+
+    /// Keeps the input from this position onwards available until `release`
+    /// is called on a copy at the same position, because it is still going to
+    /// be sliced or rewound to. Only streams that discard input that has been
+    /// read need to implement this.
+    fn retain(&self) {}
+
+    /// Undoes an earlier call to `retain` at the same position.
+    fn release(&self) {}
+
+    /// Advances past at most `max` leading items that satisfy `f`, and returns
+    /// how many items were skipped.
+    fn skip_while(&mut self, max: usize, mut f: impl FnMut(Self::Item) -> bool) -> usize {
+        let mut count = 0;
+        while count < max && self.uncons_map(|item| f(item).then_some(())).is_some() {
+            count += 1;
+        }
+        count
+    }
 }
 
-impl<'a> Stream for &'a str {
+impl Stream for &str {
     type Item = char;
     type Position = *const u8;
     type Range = Self;
@@ -29,8 +66,22 @@ impl<'a> Stream for &'a str {
         Some(output)
     }
 
-    unsafe fn between(start: Self::Position, end: Self::Position) -> Self::Range {
-        std::str::from_utf8_unchecked(<&[u8]>::between(start, end))
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range {
+        &checkpoint[..checkpoint.len() - self.len()]
+    }
+
+    fn skip_while(&mut self, max: usize, mut f: impl FnMut(Self::Item) -> bool) -> usize {
+        let mut count = 0;
+        let mut end = self.len();
+        for (i, c) in self.char_indices() {
+            if count == max || !f(c) {
+                end = i;
+                break;
+            }
+            count += 1;
+        }
+        *self = &self[end..];
+        count
     }
 }
 
@@ -54,7 +105,46 @@ impl<'a, T> Stream for &'a [T] {
         Some(output)
     }
 
-    unsafe fn between(start: Self::Position, end: Self::Position) -> Self::Range {
-        std::slice::from_raw_parts(start, end as usize - start as usize)
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range {
+        &checkpoint[..checkpoint.len() - self.len()]
+    }
+
+    fn skip_while(&mut self, max: usize, mut f: impl FnMut(Self::Item) -> bool) -> usize {
+        let count = self.iter().take(max).take_while(|&item| f(item)).count();
+        *self = &self[count..];
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{any, prelude::*};
+
+    #[test]
+    fn test_recognize_wide_slice() {
+        let numbers: &[u32] = &[1, 2, 3, 40, 5];
+        let mut input = numbers;
+        let parser = satisfy(|&n: &u32| n < 10).skip_many().recognize();
+        assert_eq!(parser.parse(&mut input), Ok(&numbers[..3]));
+        assert_eq!(input, &numbers[3..]);
+
+        let pairs: &[(u64, u64)] = &[(0, 1), (2, 3), (4, 5)];
+        let mut input = pairs;
+        let parser = chain((any(), any())).recognize();
+        assert_eq!(parser.parse(&mut input), Ok(&pairs[..2]));
+        assert_eq!(input, &pairs[2..]);
+    }
+
+    #[test]
+    fn test_slice_since() {
+        let checkpoint = "ßé€x";
+        let mut input = checkpoint;
+        satisfy(|c: char| !c.is_ascii())
+            .skip_many()
+            .parse(&mut input)
+            .unwrap();
+        assert_eq!(input.slice_since(&checkpoint), "ßé€");
+        assert_eq!(input.slice_since(&input), "");
     }
 }
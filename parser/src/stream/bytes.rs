@@ -0,0 +1,61 @@
+use super::*;
+use std::fmt;
+
+/// A stream over a byte slice that yields `u8` items rather than references.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Bytes<'a>(&'a [u8]);
+
+impl<'a> Bytes<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Bytes(bytes)
+    }
+
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a> From<&'a [u8]> for Bytes<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl fmt::Debug for Bytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("b\"")?;
+        for &byte in self.0 {
+            write!(f, "{}", std::ascii::escape_default(byte))?;
+        }
+        f.write_str("\"")
+    }
+}
+
+impl<'a> Stream for Bytes<'a> {
+    type Item = u8;
+    type Position = *const u8;
+    type Range = &'a [u8];
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn position(&self) -> Self::Position {
+        self.0.as_ptr()
+    }
+
+    fn uncons_map<O>(&mut self, f: impl FnOnce(Self::Item) -> Option<O>) -> Option<O> {
+        let (&first, rest) = self.0.split_first()?;
+        let output = f(first)?;
+        self.0 = rest;
+        Some(output)
+    }
+
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range {
+        self.0.slice_since(&checkpoint.0)
+    }
+
+    fn skip_while(&mut self, max: usize, mut f: impl FnMut(Self::Item) -> bool) -> usize {
+        self.0.skip_while(max, |&byte| f(byte))
+    }
+}
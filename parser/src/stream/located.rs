@@ -0,0 +1,152 @@
+use super::*;
+use std::fmt;
+
+/// A position in the input of a `Located` stream, with 1-based line and
+/// column numbers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    fn advance(&mut self, c: char, tab_width: usize) {
+        self.offset += c.len_utf8();
+        match c {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            '\t' if tab_width > 0 => {
+                self.column += tab_width - (self.column - 1) % tab_width;
+            }
+            _ => self.column += 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A stream that keeps track of the line and column of its current position.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Located<I> {
+    input: I,
+    location: Location,
+    tab_width: usize,
+}
+
+impl<I> Located<I> {
+    pub fn new(input: I) -> Self {
+        Located {
+            input,
+            location: Location {
+                offset: 0,
+                line: 1,
+                column: 1,
+            },
+            tab_width: 4,
+        }
+    }
+
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I> Stream for Located<I>
+where
+    I: Stream<Item = char>,
+{
+    type Item = char;
+    type Position = Location;
+    type Range = I::Range;
+
+    fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    fn position(&self) -> Self::Position {
+        self.location
+    }
+
+    fn uncons_map<O>(&mut self, f: impl FnOnce(Self::Item) -> Option<O>) -> Option<O> {
+        let location = &mut self.location;
+        let tab_width = self.tab_width;
+        self.input.uncons_map(|c| {
+            let output = f(c)?;
+            location.advance(c, tab_width);
+            Some(output)
+        })
+    }
+
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range {
+        self.input.slice_since(&checkpoint.input)
+    }
+
+    fn retain(&self) {
+        self.input.retain();
+    }
+
+    fn release(&self) {
+        self.input.release();
+    }
+
+    fn is_partial(&self) -> bool {
+        self.input.is_partial()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_located() {
+        let mut input = Located::new("ab\ncdé\tf");
+        let parser = satisfy(|c: char| c != 'f').skip_many().recognize();
+        assert_eq!(parser.parse(&mut input), Ok("ab\ncdé\t"));
+
+        let location = input.position();
+        assert_eq!(location.offset(), 8);
+        assert_eq!((location.line(), location.column()), (2, 5));
+        assert_eq!(input.into_inner(), "f");
+    }
+
+    #[test]
+    fn test_tab_width() {
+        let mut input = Located::new("a\tb").with_tab_width(8);
+        satisfy(|c| c != 'b').skip_many().parse(&mut input).unwrap();
+        assert_eq!(input.position().column(), 9);
+    }
+
+    #[test]
+    fn test_located_error() {
+        let error = chain((string("ab"), token('\n'), string("cd")))
+            .parse_to_end(Located::new("ab\nce"))
+            .unwrap_err();
+        assert_eq!(error.position().to_string(), "2:2");
+    }
+}
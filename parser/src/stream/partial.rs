@@ -0,0 +1,156 @@
+use super::*;
+
+/// A stream over a chunk of input that may be followed by more input. Parsers
+/// that run out of input on a partial stream fail with an incomplete error,
+/// after which parsing can be retried once more input is available.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Partial<I>(I);
+
+impl<I> Partial<I> {
+    pub fn new(input: I) -> Self {
+        Partial(input)
+    }
+
+    /// The underlying stream, which treats its end as the end of the input.
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I> Stream for Partial<I>
+where
+    I: Stream,
+{
+    type Item = I::Item;
+    type Position = I::Position;
+    type Range = I::Range;
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn position(&self) -> Self::Position {
+        self.0.position()
+    }
+
+    fn uncons_map<O>(&mut self, f: impl FnOnce(Self::Item) -> Option<O>) -> Option<O> {
+        self.0.uncons_map(f)
+    }
+
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range {
+        self.0.slice_since(&checkpoint.0)
+    }
+
+    fn retain(&self) {
+        self.0.retain();
+    }
+
+    fn release(&self) {
+        self.0.release();
+    }
+
+    fn is_partial(&self) -> bool {
+        true
+    }
+
+    fn skip_while(&mut self, max: usize, f: impl FnMut(Self::Item) -> bool) -> usize {
+        self.0.skip_while(max, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{byte, eof, prelude::*, skip_until, u32, Collector};
+
+    #[test]
+    fn test_partial() {
+        let error = string("abc")
+            .parse_once(&mut Partial::new("ab"))
+            .unwrap_err();
+        assert_eq!(error.needed(), Some(1));
+        assert_eq!(error.to_string(), "needs more data (at least 1)");
+
+        let error = string("abc").parse_once(&mut Partial::new("")).unwrap_err();
+        assert_eq!(error.needed(), Some(3));
+        assert!(string("abc")
+            .parse(&mut Partial::new("abd"))
+            .is_err_and(|e| !e.is_incomplete()));
+
+        let error = eof().parse(&mut Partial::new("")).unwrap_err();
+        assert!(error.is_incomplete());
+        assert_eq!(eof().parse(&mut ""), Ok(()));
+
+        let parser = choice((string("ab"), string("cd")));
+        assert!(parser
+            .parse(&mut Partial::new(""))
+            .unwrap_err()
+            .is_incomplete());
+        assert!(parser
+            .parse(&mut Partial::new("a"))
+            .unwrap_err()
+            .is_incomplete());
+        assert_eq!(parser.parse(&mut Partial::new("cd")), Ok(()));
+    }
+
+    #[test]
+    fn test_partial_many() {
+        let mut input = Partial::new("12");
+        assert!(u32().parse(&mut input).unwrap_err().is_incomplete());
+
+        let mut input = Partial::new("12;");
+        assert_eq!(u32().parse(&mut input), Ok(12));
+
+        let numbers = u32().collect_sep_by::<_, Vec<_>>(token(','));
+        assert!(numbers
+            .parse(&mut Partial::new("1,2"))
+            .unwrap_err()
+            .is_incomplete());
+        assert!(numbers
+            .parse(&mut Partial::new("1,"))
+            .unwrap_err()
+            .is_incomplete());
+        assert_eq!(numbers.parse(&mut Partial::new("1,2]")), Ok(vec![1, 2]));
+
+        let bytes = Partial::new(Bytes::new(b"12"));
+        assert!(byte::u8()
+            .parse(&mut { bytes })
+            .unwrap_err()
+            .is_incomplete());
+        assert_eq!(byte::u8().parse(&mut bytes.into_inner()), Ok(12));
+    }
+
+    #[test]
+    fn test_partial_iter() {
+        let mut iter = u32()
+            .followed_by(token(';'))
+            .iter_many(Partial::new("1;2;3"));
+        assert_eq!(iter.next(), Some((1, ';')));
+        assert_eq!(iter.next(), Some((2, ';')));
+        assert_eq!(iter.next(), None);
+        assert!(iter.error().is_some_and(Error::is_incomplete));
+        assert_eq!(iter.remaining(), Partial::new("3"));
+
+        let mut iter = u32().iter_many(Partial::new("1x").into_inner());
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert!(iter.error().is_none());
+
+        let mut iter = u32().iter_sep_by(token(','), Partial::new("1,2,3"));
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![1, 2]);
+        assert!(iter.error().is_some_and(Error::is_incomplete));
+        assert_eq!(iter.remaining(), Partial::new(",3"));
+    }
+
+    #[test]
+    fn test_partial_recover() {
+        let errors = Collector::new();
+        let parser = u32().recover_with(skip_until(token(';')).map(|()| 0), &errors);
+        let error = parser.parse(&mut Partial::new("x")).unwrap_err();
+        assert_eq!(error.needed(), Some(1));
+
+        let mut input = Partial::new("x;");
+        assert_eq!(parser.parse(&mut input), Ok(0));
+        assert_eq!(input, Partial::new(";"));
+    }
+}
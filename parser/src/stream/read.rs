@@ -0,0 +1,289 @@
+use super::*;
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Read};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+const DEFAULT_WINDOW: usize = 64 * 1024;
+
+struct Buffer<R> {
+    reader: R,
+    data: Vec<u8>,
+    /// The offset of the first byte of `data` in the input.
+    start: usize,
+    /// The offsets that are still going to be sliced or rewound to, which are
+    /// kept around regardless of the window.
+    retained: Vec<usize>,
+    done: bool,
+    error: Option<io::Error>,
+}
+
+impl<R> Buffer<R>
+where
+    R: Read,
+{
+    /// Reads from the underlying reader until the byte at `offset` is buffered
+    /// or the input ends, and discards data that lies outside of the window
+    /// and isn't retained. Data is discarded a chunk at a time, so that the
+    /// buffer isn't shifted for every byte that is read.
+    ///
+    /// Going back to data that has already been discarded ends the input with
+    /// an error.
+    fn get(&mut self, offset: usize, window: usize) -> Option<u8> {
+        let keep = self
+            .retained
+            .iter()
+            .fold(offset.saturating_sub(window), |keep, &n| keep.min(n));
+        let discard = keep.saturating_sub(self.start);
+        if discard >= CHUNK_SIZE {
+            self.data.drain(..discard.min(self.data.len()));
+            self.start += discard;
+        }
+
+        if offset < self.start {
+            self.error.get_or_insert_with(|| {
+                io::Error::other(format!(
+                    "offset {} lies outside of the buffered window starting at {}",
+                    offset, self.start,
+                ))
+            });
+            return None;
+        }
+        while offset - self.start >= self.data.len() && !self.done {
+            self.fill();
+        }
+        self.data.get(offset - self.start).copied()
+    }
+
+    fn release(&mut self, offset: usize) {
+        if let Some(i) = self.retained.iter().rposition(|&n| n == offset) {
+            self.retained.swap_remove(i);
+        }
+    }
+
+    fn fill(&mut self) {
+        let len = self.data.len();
+        self.data.resize(len + CHUNK_SIZE, 0);
+        loop {
+            match self.reader.read(&mut self.data[len..]) {
+                Ok(n) => {
+                    self.data.truncate(len + n);
+                    self.done = n == 0;
+                    return;
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => {
+                    self.data.truncate(len);
+                    self.error = Some(error);
+                    self.done = true;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Buffers the contents of a reader so that they can be parsed through a
+/// `ReadStream`, without loading the entire input into memory.
+///
+/// Apart from the `window` bytes preceding the furthest position that was read,
+/// only the input that is retained by combinators such as `recognize`,
+/// `take_while` and `attempt` is kept around, so the buffer grows as far as
+/// those need it to. Rewinding further back than that by other means ends the
+/// input, and `take_error` then returns an error.
+pub struct ReadBuffer<R> {
+    buffer: RefCell<Buffer<R>>,
+    window: usize,
+}
+
+impl<R> ReadBuffer<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        ReadBuffer::with_window(reader, DEFAULT_WINDOW)
+    }
+
+    pub fn with_window(reader: R, window: usize) -> Self {
+        ReadBuffer {
+            buffer: RefCell::new(Buffer {
+                reader,
+                data: Vec::new(),
+                start: 0,
+                retained: Vec::new(),
+                done: false,
+                error: None,
+            }),
+            window,
+        }
+    }
+
+    /// A stream over the buffered input, starting at the beginning.
+    pub fn stream(&self) -> ReadStream<'_, R> {
+        ReadStream {
+            buffer: self,
+            offset: 0,
+        }
+    }
+
+    /// The I/O error that ended the input early, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.buffer.borrow_mut().error.take()
+    }
+
+    fn get(&self, offset: usize) -> Option<u8> {
+        self.buffer.borrow_mut().get(offset, self.window)
+    }
+}
+
+/// A stream of bytes read from a `ReadBuffer`. Its position is the byte offset
+/// into the input, and its ranges are copied out of the buffer.
+pub struct ReadStream<'a, R> {
+    buffer: &'a ReadBuffer<R>,
+    offset: usize,
+}
+
+impl<R> ReadStream<'_, R> {
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<R> Copy for ReadStream<'_, R> {}
+
+impl<R> Clone for ReadStream<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> fmt::Debug for ReadStream<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadStream")
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<R> Stream for ReadStream<'_, R>
+where
+    R: Read,
+{
+    type Item = u8;
+    type Position = usize;
+    type Range = Vec<u8>;
+
+    fn is_empty(&self) -> bool {
+        self.buffer.get(self.offset).is_none()
+    }
+
+    fn position(&self) -> Self::Position {
+        self.offset
+    }
+
+    fn uncons_map<O>(&mut self, f: impl FnOnce(Self::Item) -> Option<O>) -> Option<O> {
+        let output = f(self.buffer.get(self.offset)?)?;
+        self.offset += 1;
+        Some(output)
+    }
+
+    /// Panics if the input at `checkpoint` has been discarded, which can't
+    /// happen as long as `checkpoint` is retained.
+    fn slice_since(&self, checkpoint: &Self) -> Self::Range {
+        let buffer = self.buffer.buffer.borrow();
+        assert!(
+            checkpoint.offset >= buffer.start,
+            "offset {} lies outside of the buffered window starting at {}",
+            checkpoint.offset,
+            buffer.start,
+        );
+        buffer.data[checkpoint.offset - buffer.start..self.offset - buffer.start].to_vec()
+    }
+
+    fn retain(&self) {
+        self.buffer.buffer.borrow_mut().retained.push(self.offset);
+    }
+
+    fn release(&self) {
+        self.buffer.buffer.borrow_mut().release(self.offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{byte, bytes, prelude::*, take_while};
+
+    /// A reader that hands out its input a few bytes at a time.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.0.len().min(buf.len()).min(3);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_read_stream() {
+        let buffer = ReadBuffer::new(Trickle(b"GET /index.html"));
+        let mut input = buffer.stream();
+        let method = choice((bytes(b"GET").attempt(), bytes(b"GEX"), bytes(b"POST")))
+            .followed_by(token(b' '))
+            .recognize();
+        assert_eq!(method.parse(&mut input), Ok(b"GET ".to_vec()));
+        assert_eq!(input.offset(), 4);
+
+        let path = satisfy(|b: u8| b != b' ').skip_many1().recognize();
+        assert_eq!(path.parse(&mut input), Ok(b"/index.html".to_vec()));
+        assert!(input.is_empty());
+        assert!(buffer.take_error().is_none());
+    }
+
+    #[test]
+    fn test_read_records() {
+        let records: String = (0..20_000).map(|n| format!("{}\n", n)).collect();
+        let buffer = ReadBuffer::with_window(Trickle(records.as_bytes()), 16);
+
+        let record = byte::u32().followed_by(token(b'\n'));
+        let mut expected = 0;
+        for (n, _) in record.iter_many(buffer.stream()) {
+            assert_eq!(n, expected);
+            assert!(buffer.buffer.borrow().data.len() <= 16 + 2 * CHUNK_SIZE);
+            expected += 1;
+        }
+        assert_eq!(expected, 20_000);
+        assert!(buffer.buffer.borrow().start > records.len() - CHUNK_SIZE - 16);
+    }
+
+    #[test]
+    fn test_read_window() {
+        let mut line = vec![b'a'; 200 * 1024];
+        line.push(b'\n');
+        let buffer = ReadBuffer::new(Trickle(&line));
+        let mut input = buffer.stream();
+        let parser = take_while(|b| b != b'\n');
+        assert_eq!(
+            parser.parse(&mut input).map(|line| line.len()),
+            Ok(200 * 1024)
+        );
+        assert!(buffer.buffer.borrow().retained.is_empty());
+
+        let input = [b'a'; 3 * CHUNK_SIZE];
+        let buffer = ReadBuffer::with_window(Trickle(&input), 8);
+        let start = buffer.stream();
+        let mut input = start;
+        satisfy(|b: u8| b == b'a')
+            .skip_many()
+            .parse(&mut input)
+            .unwrap();
+        assert!(start.is_empty());
+        let error = buffer.take_error().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "offset 0 lies outside of the buffered window starting at 16384"
+        );
+    }
+}
@@ -1,3 +1,21 @@
+/// Invokes `$m` once for every tuple size from 3 up to 16, passing a type
+/// parameter, a binding and an index for each element. Pairs are implemented
+/// by hand, since they are what larger tuples are built on.
+macro_rules! tuple_impls {
+    ($m:ident) => {
+        tuple_impls!(
+            @prefixes $m [(P1 a 0) (P2 b 1)]
+            (P3 c 2) (P4 d 3) (P5 e 4) (P6 f 5) (P7 g 6) (P8 h 7) (P9 i 8) (P10 j 9)
+            (P11 k 10) (P12 l 11) (P13 m 12) (P14 n 13) (P15 o 14) (P16 p 15)
+        );
+    };
+    (@prefixes $m:ident [$($done:tt)*] $next:tt $($rest:tt)*) => {
+        $m!($($done)* $next);
+        tuple_impls!(@prefixes $m [$($done)* $next] $($rest)*);
+    };
+    (@prefixes $m:ident [$($done:tt)*]) => {};
+}
+
 mod chain;
 mod choice;
 
@@ -1,17 +1,26 @@
 use super::*;
 
-pub trait ChoiceParserOnce<Input> {
+pub trait ChoiceParserOnce<Input>
+where
+    Input: Stream,
+{
     type Output;
 
-    fn parse_choice_once(self, input: &mut Input) -> Option<Self::Output>;
+    fn parse_choice_once(self, input: &mut Input) -> Result<Self::Output, Error<Input>>;
 }
 
-pub trait ChoiceParserMut<Input>: ChoiceParserOnce<Input> {
-    fn parse_choice_mut(&mut self, input: &mut Input) -> Option<Self::Output>;
+pub trait ChoiceParserMut<Input>: ChoiceParserOnce<Input>
+where
+    Input: Stream,
+{
+    fn parse_choice_mut(&mut self, input: &mut Input) -> Result<Self::Output, Error<Input>>;
 }
 
-pub trait ChoiceParser<Input>: ChoiceParserMut<Input> {
-    fn parse_choice(&self, input: &mut Input) -> Option<Self::Output>;
+pub trait ChoiceParser<Input>: ChoiceParserMut<Input>
+where
+    Input: Stream,
+{
+    fn parse_choice(&self, input: &mut Input) -> Result<Self::Output, Error<Input>>;
 }
 
 pub struct Choice<P, O> {
@@ -36,10 +45,11 @@ where
 impl<P, I, O> ParserOnce<I> for Choice<P, O>
 where
     P: ChoiceParserOnce<I, Output = O>,
+    I: Stream,
 {
     type Output = O;
 
-    fn parse_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser.parse_choice_once(input)
     }
 }
@@ -47,8 +57,9 @@ where
 impl<P, I, O> ParserMut<I> for Choice<P, O>
 where
     P: ChoiceParserMut<I, Output = O>,
+    I: Stream,
 {
-    fn parse_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser.parse_choice_mut(input)
     }
 }
@@ -56,8 +67,9 @@ where
 impl<P, I, O> Parser<I> for Choice<P, O>
 where
     P: ChoiceParser<I, Output = O>,
+    I: Stream,
 {
-    fn parse(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.parser.parse_choice(input)
     }
 }
@@ -65,6 +77,7 @@ where
 pub fn choice<P, I, O>(parser: P) -> Choice<P, O>
 where
     P: ChoiceParserOnce<I, Output = O>,
+    I: Stream,
 {
     Choice {
         parser,
@@ -80,7 +93,7 @@ where
 {
     type Output = O;
 
-    fn parse_choice_once(self, input: &mut I) -> Option<Self::Output> {
+    fn parse_choice_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.0.or(self.1).parse_once(input)
     }
 }
@@ -91,7 +104,7 @@ where
     P2: ParserMut<I, Output = O>,
     I: Stream,
 {
-    fn parse_choice_mut(&mut self, input: &mut I) -> Option<Self::Output> {
+    fn parse_choice_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.0.by_mut_ref().or(&mut self.1).parse_mut(input)
     }
 }
@@ -102,149 +115,53 @@ where
     P2: Parser<I, Output = O>,
     I: Stream,
 {
-    fn parse_choice(&self, input: &mut I) -> Option<Self::Output> {
+    fn parse_choice(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
         self.0.by_ref().or(&self.1).parse(input)
     }
 }
 
-impl<P1, P2, P3, I, O> ChoiceParserOnce<I> for (P1, P2, P3)
-where
-    P1: ParserOnce<I, Output = O>,
-    P2: ParserOnce<I, Output = O>,
-    P3: ParserOnce<I, Output = O>,
-    I: Stream,
-{
-    type Output = O;
-
-    fn parse_choice_once(self, input: &mut I) -> Option<Self::Output> {
-        self.0.or(choice((self.1, self.2))).parse_once(input)
-    }
-}
+/// Tries the first element, followed by a choice between the remaining
+/// elements.
+macro_rules! choice_impls {
+    (($P1:ident $p1:ident $i1:tt) $(($P:ident $p:ident $i:tt))+) => {
+        impl<$P1, $($P,)+ I, O> ChoiceParserOnce<I> for ($P1, $($P,)+)
+        where
+            $P1: ParserOnce<I, Output = O>,
+            $($P: ParserOnce<I, Output = O>,)+
+            I: Stream,
+        {
+            type Output = O;
 
-impl<P1, P2, P3, I, O> ChoiceParserMut<I> for (P1, P2, P3)
-where
-    P1: ParserMut<I, Output = O>,
-    P2: ParserMut<I, Output = O>,
-    P3: ParserMut<I, Output = O>,
-    I: Stream,
-{
-    fn parse_choice_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .by_mut_ref()
-            .or(choice((&mut self.1, &mut self.2)))
-            .parse_mut(input)
-    }
-}
-
-impl<P1, P2, P3, I, O> ChoiceParser<I> for (P1, P2, P3)
-where
-    P1: Parser<I, Output = O>,
-    P2: Parser<I, Output = O>,
-    P3: Parser<I, Output = O>,
-    I: Stream,
-{
-    fn parse_choice(&self, input: &mut I) -> Option<Self::Output> {
-        self.0.by_ref().or(choice((&self.1, &self.2))).parse(input)
-    }
-}
-
-impl<P1, P2, P3, P4, I, O> ChoiceParserOnce<I> for (P1, P2, P3, P4)
-where
-    P1: ParserOnce<I, Output = O>,
-    P2: ParserOnce<I, Output = O>,
-    P3: ParserOnce<I, Output = O>,
-    P4: ParserOnce<I, Output = O>,
-    I: Stream,
-{
-    type Output = O;
-
-    fn parse_choice_once(self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .or(choice((self.1, self.2, self.3)))
-            .parse_once(input)
-    }
-}
-
-impl<P1, P2, P3, P4, I, O> ChoiceParserMut<I> for (P1, P2, P3, P4)
-where
-    P1: ParserMut<I, Output = O>,
-    P2: ParserMut<I, Output = O>,
-    P3: ParserMut<I, Output = O>,
-    P4: ParserMut<I, Output = O>,
-    I: Stream,
-{
-    fn parse_choice_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .by_mut_ref()
-            .or(choice((&mut self.1, &mut self.2, &mut self.3)))
-            .parse_mut(input)
-    }
-}
-
-impl<P1, P2, P3, P4, I, O> ChoiceParser<I> for (P1, P2, P3, P4)
-where
-    P1: Parser<I, Output = O>,
-    P2: Parser<I, Output = O>,
-    P3: Parser<I, Output = O>,
-    P4: Parser<I, Output = O>,
-    I: Stream,
-{
-    fn parse_choice(&self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .by_ref()
-            .or(choice((&self.1, &self.2, &self.3)))
-            .parse(input)
-    }
-}
-
-impl<P1, P2, P3, P4, P5, I, O> ChoiceParserOnce<I> for (P1, P2, P3, P4, P5)
-where
-    P1: ParserOnce<I, Output = O>,
-    P2: ParserOnce<I, Output = O>,
-    P3: ParserOnce<I, Output = O>,
-    P4: ParserOnce<I, Output = O>,
-    P5: ParserOnce<I, Output = O>,
-    I: Stream,
-{
-    type Output = O;
+            fn parse_choice_once(self, input: &mut I) -> Result<Self::Output, Error<I>> {
+                self.$i1.or(choice(($(self.$i,)+))).parse_once(input)
+            }
+        }
 
-    fn parse_choice_once(self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .or(choice((self.1, self.2, self.3, self.4)))
-            .parse_once(input)
-    }
-}
+        impl<$P1, $($P,)+ I, O> ChoiceParserMut<I> for ($P1, $($P,)+)
+        where
+            $P1: ParserMut<I, Output = O>,
+            $($P: ParserMut<I, Output = O>,)+
+            I: Stream,
+        {
+            fn parse_choice_mut(&mut self, input: &mut I) -> Result<Self::Output, Error<I>> {
+                self.$i1
+                    .by_mut_ref()
+                    .or(choice(($(&mut self.$i,)+)))
+                    .parse_mut(input)
+            }
+        }
 
-impl<P1, P2, P3, P4, P5, I, O> ChoiceParserMut<I> for (P1, P2, P3, P4, P5)
-where
-    P1: ParserMut<I, Output = O>,
-    P2: ParserMut<I, Output = O>,
-    P3: ParserMut<I, Output = O>,
-    P4: ParserMut<I, Output = O>,
-    P5: ParserMut<I, Output = O>,
-    I: Stream,
-{
-    fn parse_choice_mut(&mut self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .by_mut_ref()
-            .or(choice((&mut self.1, &mut self.2, &mut self.3, &mut self.4)))
-            .parse_mut(input)
-    }
+        impl<$P1, $($P,)+ I, O> ChoiceParser<I> for ($P1, $($P,)+)
+        where
+            $P1: Parser<I, Output = O>,
+            $($P: Parser<I, Output = O>,)+
+            I: Stream,
+        {
+            fn parse_choice(&self, input: &mut I) -> Result<Self::Output, Error<I>> {
+                self.$i1.by_ref().or(choice(($(&self.$i,)+))).parse(input)
+            }
+        }
+    };
 }
 
-impl<P1, P2, P3, P4, P5, I, O> ChoiceParser<I> for (P1, P2, P3, P4, P5)
-where
-    P1: Parser<I, Output = O>,
-    P2: Parser<I, Output = O>,
-    P3: Parser<I, Output = O>,
-    P4: Parser<I, Output = O>,
-    P5: Parser<I, Output = O>,
-    I: Stream,
-{
-    fn parse_choice(&self, input: &mut I) -> Option<Self::Output> {
-        self.0
-            .by_ref()
-            .or(choice((&self.1, &self.2, &self.3, &self.4)))
-            .parse(input)
-    }
-}
+tuple_impls!(choice_impls);